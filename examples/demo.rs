@@ -2,9 +2,11 @@
 
 use eframe::{self, CreationContext, egui};
 use egui::TextEdit;
-use egui_code_editor::{self, CodeEditor, ColorTheme, Completer, Syntax, highlighting::Token};
+use egui_code_editor::{
+    self, CodeEditor, ColorTheme, Completer, Syntax, ThemeEditor, highlighting::Token,
+};
 
-const THEMES: [ColorTheme; 8] = [
+const THEMES: [ColorTheme; 10] = [
     ColorTheme::AYU,
     ColorTheme::AYU_MIRAGE,
     ColorTheme::AYU_DARK,
@@ -13,9 +15,86 @@ const THEMES: [ColorTheme; 8] = [
     ColorTheme::GRUVBOX,
     ColorTheme::GRUVBOX_LIGHT,
     ColorTheme::SONOKAI,
+    ColorTheme::HIGH_CONTRAST_DARK,
+    ColorTheme::HIGH_CONTRAST_LIGHT,
 ];
 
-const SYNTAXES: [SyntaxDemo; 5] = [
+const SYNTAXES: [SyntaxDemo; 12] = [
+    SyntaxDemo::new(
+        "Go",
+        r#"package main
+
+import "fmt"
+
+// Sum adds numbers sent over a channel.
+func Sum(in <-chan int) (total int, err error) {
+    for v := range in {
+        total += v
+    }
+    return total, nil
+}
+
+func main() {
+    ch := make(chan int, 3)
+    ch <- 1; ch <- 2; ch <- 3
+    close(ch)
+    fmt.Println(Sum(ch))
+}"#,
+    ),
+    SyntaxDemo::new(
+        "Java",
+        r#"import java.util.List;
+
+/* Greets everyone in the list */
+public class Greeter {
+    private final String greeting = "Hello";
+
+    @Override
+    public String toString() {
+        return """
+            Greeter
+            """;
+    }
+
+    public static void main(String[] args) {
+        List<String> names = List.of("Ann", "Bob");
+        for (String name : names) {
+            System.out.println(name.length() > 0 ? name : null);
+        }
+    }
+}"#,
+    ),
+    SyntaxDemo::new(
+        "JavaScript",
+        r#"// Fetch users and render them
+const pattern = /^user-(\d+)$/i;
+
+export async function render(root) {
+    const users = await fetch(`/api/users?limit=${10}`)
+        .then((r) => r.json());
+    return users
+        .filter((u) => pattern.test(u.id) && u.age / 2 > 9)
+        .map((u) => <li key={u.id}>{u.name ?? "unknown"}</li>);
+}"#,
+    ),
+    SyntaxDemo::new(
+        "Kotlin",
+        r#"data class User(val name: String, val age: Int)
+
+// Adults sorted by name
+fun adults(users: List<User>): List<String> =
+    users.filter { it.age >= 18 }
+        .sortedBy { it.name }
+        .map { "${it.name} (${it.age})" }
+
+@JvmStatic
+fun main() {
+    val banner = """
+        |Users:
+        """.trimMargin()
+    println(banner + adults(listOf(User("Ann", 30))))
+}"#,
+    ),
     SyntaxDemo::new(
         "Lua",
         r#"-- Binary Search
@@ -59,7 +138,7 @@ CodeEditor::default()
     .id_source("code editor")
     .with_rows(12)
     .with_fontsize(14.0)
-    .with_theme(self.theme)
+    .with_theme(self.theme.to_owned())
     .with_syntax(self.syntax.to_owned())
     .with_numlines(true)
     .vscroll(true)
@@ -88,6 +167,64 @@ WITH employee_ranking AS (
   FROM employee
 )"#,
     ),
+    SyntaxDemo::new(
+        "Swift",
+        r#"import Foundation
+
+struct Point: Equatable {
+    var x: Double
+    var y: Double
+
+    /// Distance to the origin
+    func length() -> Double {
+        return (x * x + y * y).squareRoot()
+    }
+}
+
+@MainActor
+func describe(_ points: [Point]) -> String {
+    guard let first = points.first else { return "nil" }
+    #if DEBUG
+    print(first)
+    #endif
+    return "\(points.count) points"
+}"#,
+    ),
+    SyntaxDemo::new(
+        "TypeScript",
+        r#"interface Point {
+    readonly x: number;
+    y?: number;
+}
+
+type Partial2D = Partial<Point>;
+
+function norm(p: Point): number {
+    const y = p.y ?? 0;
+    return Math.sqrt(p.x * p.x + y * y);
+}
+
+export const origin: Point = { x: 0, y: undefined };"#,
+    ),
+    SyntaxDemo::new(
+        "Zig",
+        r#"const std = @import("std");
+
+// Returns the largest item or null
+fn max(items: []const i32) ?i32 {
+    if (items.len == 0) return null;
+    var best: i32 = items[0];
+    for (items[1..]) |item| {
+        if (item > best) best = item;
+    }
+    return best;
+}
+
+pub fn main() !void {
+    const stdout = std.io.getStdOut().writer();
+    try stdout.print("{?}\n", .{max(&[_]i32{ 3, 7, 1 })});
+}"#,
+    ),
 ];
 
 #[derive(Clone, Copy)]
@@ -103,11 +240,18 @@ impl SyntaxDemo {
     fn syntax(&self) -> Syntax {
         match self.name {
             "Assembly" => Syntax::asm(),
+            "Go" => Syntax::go(),
+            "Java" => Syntax::java(),
+            "JavaScript" => Syntax::javascript(),
+            "Kotlin" => Syntax::kotlin(),
             "Lua" => Syntax::lua(),
             "Python" => Syntax::python(),
             "Rust" => Syntax::rust(),
             "Shell" => Syntax::shell(),
             "SQL" => Syntax::sql(),
+            "Swift" => Syntax::swift(),
+            "TypeScript" => Syntax::typescript(),
+            "Zig" => Syntax::zig(),
             _ => Syntax::shell(),
         }
     }
@@ -149,10 +293,11 @@ struct CodeEditorDemo {
     example: bool,
     shift: isize,
     numlines_only_natural: bool,
+    theme_editor: Option<ThemeEditor>,
 }
 impl CodeEditorDemo {
     fn new(_cc: &CreationContext) -> Self {
-        let rust = SYNTAXES[2];
+        let rust = SYNTAXES[6];
         CodeEditorDemo {
             code: rust.example.to_string(),
             text: String::default(),
@@ -162,17 +307,25 @@ impl CodeEditorDemo {
             example: true,
             shift: 0,
             numlines_only_natural: false,
+            theme_editor: None,
         }
     }
 }
 impl eframe::App for CodeEditorDemo {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::SidePanel::left("theme_picker").show(ctx, |ui| {
-            ui.heading("Theme");
+            ui.horizontal(|h| {
+                h.heading("Theme");
+                if h.button("Edit").clicked() {
+                    self.theme_editor = Some(
+                        ThemeEditor::new(self.theme.to_owned()).with_syntax(self.syntax.to_owned()),
+                    );
+                }
+            });
             egui::ScrollArea::both().show(ui, |ui| {
                 for theme in THEMES.iter() {
                     if ui
-                        .selectable_value(&mut self.theme, *theme, theme.name())
+                        .selectable_value(&mut self.theme, theme.clone(), theme.name())
                         .clicked()
                     {
                         if theme.is_dark() {
@@ -185,6 +338,21 @@ impl eframe::App for CodeEditorDemo {
             });
         });
 
+        if let Some(theme_editor) = &mut self.theme_editor {
+            let mut open = true;
+            egui::Window::new("Theme Editor")
+                .open(&mut open)
+                .default_width(900.0)
+                .show(ctx, |ui| {
+                    if theme_editor.show(ui) {
+                        self.theme = theme_editor.theme().to_owned();
+                    }
+                });
+            if !open {
+                self.theme_editor = None;
+            }
+        }
+
         egui::SidePanel::right("syntax_picker").show(ctx, |ui| {
             ui.horizontal(|h| {
                 h.heading("Syntax");
@@ -218,7 +386,7 @@ impl eframe::App for CodeEditorDemo {
                 .id_source("code editor")
                 .with_rows(10)
                 .with_fontsize(14.0)
-                .with_theme(self.theme)
+                .with_theme(self.theme.to_owned())
                 .with_syntax(self.syntax.to_owned())
                 .with_numlines(true)
                 .with_numlines_shift(self.shift)
@@ -1,16 +1,22 @@
 use colorful::{Color, Colorful};
-use egui_code_editor::{Syntax, Token, TokenType};
+use egui_code_editor::{AnsiRenderer, ColorTheme, Syntax, Token, TokenType};
 
 fn color(token: TokenType) -> Color {
     match token {
+        TokenType::Attribute => Color::LightSalmon1,
+        TokenType::Column(_) => Color::SkyBlue1,
         TokenType::Comment(_) => Color::Grey37,
+        TokenType::Emphasis => Color::Plum1,
         TokenType::Function => Color::Yellow3b,
+        TokenType::Heading => Color::DeepSkyBlue1,
+        TokenType::Key => Color::LightGoldenrod1,
         TokenType::Keyword => Color::IndianRed1c,
         TokenType::Literal => Color::NavajoWhite1,
         TokenType::Numeric(_) => Color::MediumPurple,
         TokenType::Punctuation(_) => Color::Orange3,
         TokenType::Special => Color::Cyan,
         TokenType::Str(_) => Color::Green,
+        TokenType::Tag => Color::LightCoral,
         TokenType::Type => Color::GreenYellow,
         TokenType::Whitespace(_) => Color::White,
         TokenType::Hyperlink => Color::Blue3b,
@@ -24,7 +30,7 @@ fn main() {
      .id_source("code editor")
      .with_rows(12)
      .with_fontsize(14.0)
-     .with_theme(self.theme)
+     .with_theme(self.theme.to_owned())
      .with_syntax(self.syntax.to_owned())
      .with_numlines(true)
      .vscroll(true)
@@ -35,4 +41,13 @@ fn main() {
     for token in Token::default().tokens(&syntax, text) {
         print!("{}", token.buffer().color(color(token.ty())));
     }
+
+    // Same colors as the egui editor with a built-in theme
+    let ansi = AnsiRenderer::default()
+        .with_theme(ColorTheme::GRUVBOX)
+        .with_syntax(syntax)
+        .with_numlines(true)
+        .with_gutter(true)
+        .render(text);
+    print!("{ansi}");
 }
@@ -0,0 +1,202 @@
+//! Highlights files or stdin for terminals or as HTML, a small `bat`-like tool.
+use egui_code_editor::{
+    AnsiRenderer, ColorDepth, ColorTheme, DEFAULT_THEMES, HtmlRenderer, Syntax,
+};
+use std::io::{self, IsTerminal, Read, Write};
+use std::ops::RangeInclusive;
+use std::process::{Command, ExitCode, Stdio};
+use std::{env, fs};
+
+const HELP: &str = "\
+Highlights files or stdin with egui_code_editor themes.
+
+Usage: code-highlight [OPTIONS] [FILE]...
+
+Options:
+  -l, --language <NAME>  Language by name or extension, guessed from file names otherwise
+  -t, --theme <NAME>     Theme name [default: Gruvbox]
+  -n, --numbers          Show line numbers
+  -r, --range <N:M>      Show only lines N to M, either end may be omitted
+      --html             Write HTML instead of ANSI escape sequences
+      --color <WHEN>     auto, always or never [default: auto]
+      --paging <WHEN>    auto, always or never [default: auto]
+      --list-themes      List theme names
+      --list-languages   List language names
+  -h, --help             Print help
+";
+
+#[derive(Default)]
+struct Options {
+    language: Option<String>,
+    theme: Option<String>,
+    numbers: bool,
+    range: Option<RangeInclusive<isize>>,
+    html: bool,
+    color: Option<String>,
+    paging: Option<String>,
+    files: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("code-highlight: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let mut options = Options::default();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("missing value of `{name}`"));
+        match arg.as_str() {
+            "-l" | "--language" => options.language = Some(value(&arg)?),
+            "-t" | "--theme" => options.theme = Some(value(&arg)?),
+            "-n" | "--numbers" => options.numbers = true,
+            "-r" | "--range" => options.range = Some(range(&value(&arg)?)?),
+            "--html" => options.html = true,
+            "--color" => options.color = Some(value(&arg)?),
+            "--paging" => options.paging = Some(value(&arg)?),
+            "--list-themes" => {
+                let names = DEFAULT_THEMES.map(|theme| theme.name().to_string());
+                print(&(names.join("\n") + "\n"));
+                return Ok(());
+            }
+            "--list-languages" => {
+                let syntaxes = Syntax::builtin();
+                let names = syntaxes.iter().map(Syntax::language).collect::<Vec<_>>();
+                print(&(names.join("\n") + "\n"));
+                return Ok(());
+            }
+            "-h" | "--help" => {
+                print(HELP);
+                return Ok(());
+            }
+            "-" => options.files.push(arg),
+            _ if arg.starts_with('-') => {
+                return Err(format!("unknown option `{arg}`, see `--help`"));
+            }
+            _ => options.files.push(arg),
+        }
+    }
+    if options.files.is_empty() {
+        options.files.push(String::from("-"));
+    }
+
+    let theme = match &options.theme {
+        Some(name) => DEFAULT_THEMES
+            .into_iter()
+            .find(|theme| theme.name().eq_ignore_ascii_case(name))
+            .ok_or(format!("unknown theme `{name}`, see `--list-themes`"))?,
+        None => ColorTheme::GRUVBOX,
+    };
+    let language = match &options.language {
+        Some(name) => Some(
+            Syntax::by_name(name)
+                .or_else(|| Syntax::by_extension(name))
+                .ok_or(format!("unknown language `{name}`, see `--list-languages`"))?,
+        ),
+        None => None,
+    };
+    let tty = io::stdout().is_terminal();
+    let depth = match options.color.as_deref() {
+        None | Some("auto") if tty => ColorDepth::detect(),
+        None | Some("auto") | Some("never") => ColorDepth::Plain,
+        Some("always") => match ColorDepth::detect() {
+            ColorDepth::Plain => ColorDepth::Ansi256,
+            depth => depth,
+        },
+        Some(when) => {
+            return Err(format!(
+                "`--color` must be auto, always or never, not `{when}`"
+            ));
+        }
+    };
+
+    let mut output = String::new();
+    for file in &options.files {
+        let code = if file == "-" {
+            let mut code = String::new();
+            io::stdin()
+                .read_to_string(&mut code)
+                .map_err(|e| format!("stdin: {e}"))?;
+            code
+        } else {
+            fs::read_to_string(file).map_err(|e| format!("{file}: {e}"))?
+        };
+        let syntax = language
+            .clone()
+            .or_else(|| Syntax::for_path(file))
+            .unwrap_or_else(|| Syntax::simple(""));
+        let all = isize::MIN..=isize::MAX;
+        let range = options.range.clone().unwrap_or(all);
+        if options.html {
+            output += &HtmlRenderer::default()
+                .with_theme(theme.clone())
+                .with_syntax(syntax)
+                .with_numlines(options.numbers)
+                .with_line_range(range)
+                .render(&code);
+        } else {
+            output += &AnsiRenderer::default()
+                .with_theme(theme.clone())
+                .with_syntax(syntax)
+                .with_depth(depth)
+                .with_numlines(options.numbers)
+                .with_gutter(options.numbers)
+                .with_line_range(range)
+                .render(&code);
+        }
+    }
+
+    let paging = match options.paging.as_deref() {
+        None | Some("auto") => tty,
+        Some("always") => true,
+        Some("never") => false,
+        Some(when) => {
+            return Err(format!(
+                "`--paging` must be auto, always or never, not `{when}`"
+            ));
+        }
+    };
+    if !(paging && page(&output).is_ok()) {
+        print(&output);
+    }
+    Ok(())
+}
+
+// Closed pipe, as in `| head`, is not an error.
+fn print(text: &str) {
+    let _ = io::stdout().write_all(text.as_bytes());
+}
+
+// Lines from `N:M`, `N:`, `:M` or a single `N`.
+fn range(value: &str) -> Result<RangeInclusive<isize>, String> {
+    let number = |n: &str, default| {
+        if n.is_empty() {
+            Ok(default)
+        } else {
+            n.parse::<isize>()
+                .map_err(|_| format!("malformed line range `{value}`"))
+        }
+    };
+    match value.split_once(':') {
+        Some((start, end)) => Ok(number(start, isize::MIN)?..=number(end, isize::MAX)?),
+        None => number(value, 0).map(|n| n..=n),
+    }
+}
+
+// Pipes output through `$PAGER`, or `less` quitting if it fits one screen.
+fn page(output: &str) -> io::Result<()> {
+    let pager = env::var("PAGER").unwrap_or_else(|_| String::from("less -FRX"));
+    let mut words = pager.split_whitespace();
+    let mut command = Command::new(words.next().unwrap_or("less"));
+    let mut child = command.args(words).stdin(Stdio::piped()).spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(output.as_bytes());
+    }
+    child.wait().map(|_| ())
+}
@@ -30,6 +30,15 @@ impl From<&Syntax> for Trie {
                 .iter()
                 .for_each(|word| trie.push(&word.to_lowercase()));
         }
+        // Completion prefix stops at `@` or `#`, so words like `@vertex` are completed without it.
+        syntax
+            .keywords
+            .iter()
+            .chain(&syntax.types)
+            .chain(&syntax.special)
+            .filter(|word| syntax.is_prefixed(word))
+            .filter_map(|word| word.char_indices().nth(1).map(|(i, _)| &word[i..]))
+            .for_each(|word| trie.push(word));
         trie
     }
 }
@@ -129,6 +138,7 @@ impl Completer {
         }
         let ctx = editor_output.response.ctx.clone();
         let galley = &editor_output.galley;
+        let chrome = theme.chrome_colors();
 
         if editor_output.response.changed() {
             // Update Competer Dictionary
@@ -194,7 +204,7 @@ impl Completer {
                     cursor_rect,
                     editor_output.response.layer_id,
                 )
-                .frame(Frame::popup(&ctx.global_style()).fill(theme.bg()))
+                .frame(Frame::popup(&ctx.global_style()).fill(chrome.popup.into()))
                 .sense(Sense::empty())
                 .show(|ui| {
                     ui.response().sense = Sense::empty();
@@ -227,11 +237,11 @@ impl Completer {
                                     egui::Button::new(colored_text)
                                         .sense(Sense::empty())
                                         .frame(true)
-                                        .fill(theme.bg())
+                                        .fill(chrome.popup)
                                         .stroke(if selected {
                                             Stroke::new(
                                                 ui.style().visuals.widgets.hovered.bg_stroke.width,
-                                                theme.type_color(TokenType::Literal),
+                                                chrome.popup_selection,
                                             )
                                         } else {
                                             Stroke::NONE
@@ -1,7 +1,7 @@
 #[cfg(feature = "editor")]
 use super::Editor;
 
-use super::syntax::{Syntax, TokenType, QUOTES, SEPARATORS};
+use super::syntax::{Rule, Syntax, TokenType, QUOTES, SEPARATORS};
 use std::mem;
 
 #[derive(Default, Debug, PartialEq, PartialOrd, Eq, Ord)]
@@ -25,7 +25,66 @@ impl Token {
         &self.buffer
     }
 
-    fn first(&mut self, c: char, syntax: &Syntax) -> Option<Self> {
+    #[cfg(feature = "egui")]
+    /// Syntax highlighting
+    pub fn highlight<T: Editor>(&mut self, editor: &T, text: &str) -> LayoutJob {
+        let mut lexer = Lexer::default();
+        let mut job = LayoutJob::default();
+        for c in text.chars() {
+            for token in lexer.automata(c, editor.syntax()) {
+                editor.append(&mut job, &token);
+            }
+        }
+        for token in lexer.finish(editor.syntax()) {
+            editor.append(&mut job, &token);
+        }
+        job
+    }
+
+    /// Lexer
+    pub fn tokens(&mut self, syntax: &Syntax, text: &str) -> Vec<Self> {
+        let mut lexer = Lexer::default();
+        let mut tokens: Vec<Self> = text
+            .chars()
+            .flat_map(|c| lexer.automata(c, syntax))
+            .collect();
+        tokens.extend(lexer.finish(syntax));
+        tokens
+    }
+}
+
+#[derive(Default)]
+// Token being read and the state carried between tokens.
+struct Lexer {
+    ty: TokenType,
+    buffer: String,
+    // Last drained token except whitespaces and comments.
+    prev: TokenType,
+    // Tag name is expected after `<`.
+    tag: bool,
+    // Inside of `<...>` markup tag.
+    in_tag: bool,
+    // Anything but whitespace was drained since the last new line.
+    midline: bool,
+    // Words waiting for a key separator.
+    pending: Vec<Token>,
+    // Inside of `[section]` header.
+    section: bool,
+    line_started: bool,
+    // Nesting of `{}` blocks.
+    depth: usize,
+    // Block opened next holds rules, as in `@media {}`.
+    grouping: bool,
+    // Nesting of `()` in selectors and at-rules.
+    parens: usize,
+    // Pseudo-class name is expected after `:`.
+    pseudo: bool,
+    // Inside of tab indented line.
+    recipe: bool,
+}
+
+impl Lexer {
+    fn first(&mut self, c: char, syntax: &Syntax) -> Option<Token> {
         self.buffer.push(c);
         let mut token = None;
         self.ty = match c {
@@ -34,72 +93,323 @@ impl Token {
                 token = self.drain(self.ty);
                 TokenType::Whitespace(c)
             }
+            c if syntax.has_rule(Rule::Headings(c)) && !self.midline => TokenType::Heading,
             c if syntax.is_keyword(c.to_string().as_str()) => TokenType::Keyword,
             c if syntax.is_type(c.to_string().as_str()) => TokenType::Type,
             c if syntax.is_special(c.to_string().as_str()) => TokenType::Special,
             c if syntax.comment == c.to_string().as_str() => TokenType::Comment(false),
+            c if syntax.has_rule(Rule::Comment(c)) => TokenType::Comment(false),
             c if syntax.comment_multiline[0] == c.to_string().as_str() => TokenType::Comment(true),
-            _ => TokenType::from(c),
+            '_' if syntax.has_rule(Rule::Emphasis) => TokenType::Punctuation(c),
+            _ => match TokenType::from(c) {
+                TokenType::Str(q) if !self.opens_str(q, syntax) => TokenType::Punctuation(q),
+                ty => ty,
+            },
         };
+        self.open_tag(c, syntax);
         token
     }
 
-    fn drain(&mut self, ty: TokenType) -> Option<Self> {
+    fn open_tag(&mut self, c: char, syntax: &Syntax) {
+        if c == '<' && syntax.has_rule(Rule::Markup) {
+            self.tag = true;
+            self.in_tag = true;
+        } else if c == '<' && syntax.has_rule(Rule::Jsx) {
+            self.tag = self.expression_start();
+        }
+    }
+
+    fn drain(&mut self, ty: TokenType) -> Option<Token> {
         let mut token = None;
-        if !self.buffer().is_empty() {
-            token = Some(Token {
-                buffer: mem::take(&mut self.buffer),
-                ty: self.ty,
-            });
+        if !self.buffer.is_empty() {
+            let drained = match self.ty {
+                TokenType::Literal | TokenType::Keyword | TokenType::Type | TokenType::Special
+                    if self.tag =>
+                {
+                    TokenType::Tag
+                }
+                TokenType::Literal | TokenType::Keyword | TokenType::Type | TokenType::Special
+                    if self.in_tag =>
+                {
+                    TokenType::Attribute
+                }
+                ty => ty,
+            };
+            self.tag &= matches!(drained, TokenType::Punctuation('<' | '/' | '!' | '?'));
+            self.in_tag &= !matches!(drained, TokenType::Punctuation('>') | TokenType::Comment(_));
+            match drained {
+                TokenType::Whitespace('\n') => self.midline = false,
+                TokenType::Whitespace(_) => (),
+                _ => self.midline = true,
+            }
+            if !matches!(drained, TokenType::Whitespace(_) | TokenType::Comment(_)) {
+                self.prev = drained;
+            }
+            token = Some(Token::new(drained, mem::take(&mut self.buffer)));
         }
         self.ty = ty;
         token
     }
 
-    fn push_drain(&mut self, c: char, ty: TokenType) -> Option<Self> {
+    // Operand is expected next, so `/` starts a regex and `<` starts a tag.
+    fn expression_start(&self) -> bool {
+        match self.prev {
+            TokenType::Keyword | TokenType::Unknown => true,
+            TokenType::Punctuation(c) => !matches!(c, ')' | ']'),
+            _ => false,
+        }
+    }
+
+    // Quotes are plain text in markup outside of tags and in prose, except backticks.
+    fn opens_str(&self, q: char, syntax: &Syntax) -> bool {
+        !(syntax.has_rule(Rule::Markup) && !self.in_tag || syntax.has_rule(Rule::Prose) && q != '`')
+    }
+
+    // `*text*` is closed by the same run of markers it was opened with.
+    fn emphasis_closed(&self) -> bool {
+        let Some(m) = self.buffer.chars().next() else {
+            return false;
+        };
+        let run = self.buffer.chars().take_while(|&c| c == m).count();
+        let content = self.buffer.len() - run;
+        self.buffer.len() > 2 * run
+            && self.buffer.ends_with(&m.to_string().repeat(run))
+            && !self.buffer[..content].ends_with(char::is_whitespace)
+    }
+
+    // Closing bracket of `$(name)` or `${name}`.
+    fn variable_close(&self, syntax: &Syntax) -> Option<char> {
+        let mut chars = self.buffer.chars();
+        let (sigil, open) = (chars.next()?, chars.next()?);
+        let close = match open {
+            '(' => ')',
+            '{' => '}',
+            _ => return None,
+        };
+        syntax.has_rule(Rule::Variables(sigil)).then_some(close)
+    }
+
+    // Closing delimiter of a sigil like `~r/` or `%w[`.
+    fn sigil_close(&self, c: char, syntax: &Syntax) -> Option<char> {
+        let mut chars = self.buffer.chars();
+        let sigil = chars.next()?;
+        let name = chars.as_str();
+        if !syntax.has_rule(Rule::Sigils(sigil))
+            || name.is_empty()
+            || !name.chars().all(char::is_alphabetic)
+        {
+            return None;
+        }
+        match c {
+            '(' => Some(')'),
+            '[' => Some(']'),
+            '{' => Some('}'),
+            '<' => Some('>'),
+            '/' | '|' | '"' | '\'' => Some(c),
+            _ => None,
+        }
+    }
+
+    fn triple_quoted(&self, q: char) -> bool {
+        self.buffer.starts_with(&q.to_string().repeat(3))
+    }
+
+    fn regex_start(&self, c: char, syntax: &Syntax) -> bool {
+        let open = format!("{}{c}", self.buffer);
+        self.buffer == "/"
+            && c != '\n'
+            && syntax.has_rule(Rule::Regex)
+            && !self.tag
+            && self.expression_start()
+            && !syntax.comment.starts_with(&open)
+            && !syntax.comment_multiline[0].starts_with(&open)
+    }
+
+    // `/pattern/` or `~r/pattern/` is closed, only flags like `gi` may follow.
+    fn regex_closed(&self) -> bool {
+        let Some((_, pattern)) = self.buffer.split_once('/') else {
+            return false;
+        };
+        let pattern = pattern.trim_end_matches(char::is_alphabetic);
+        pattern
+            .strip_suffix('/')
+            .is_some_and(|pattern| !pattern.ends_with('\\'))
+    }
+
+    fn push_drain(&mut self, c: char, ty: TokenType) -> Option<Token> {
         self.buffer.push(c);
         self.drain(ty)
     }
 
-    fn drain_push(&mut self, c: char, ty: TokenType) -> Option<Self> {
+    fn drain_push(&mut self, c: char, ty: TokenType) -> Option<Token> {
         let token = self.drain(self.ty);
         self.buffer.push(c);
         self.ty = ty;
         token
     }
 
-    #[cfg(feature = "egui")]
-    /// Syntax highlighting
-    pub fn highlight<T: Editor>(&mut self, editor: &T, text: &str) -> LayoutJob {
-        *self = Token::default();
-        let mut job = LayoutJob::default();
-        for c in text.chars() {
-            for token in self.automata(c, editor.syntax()) {
-                editor.append(&mut job, &token);
+    fn finish(&mut self, syntax: &Syntax) -> Vec<Token> {
+        let last = self.drain(TokenType::Unknown).into_iter().collect();
+        let mut tokens = self.keys(last, syntax);
+        tokens.append(&mut self.pending);
+        tokens
+    }
+
+    // Marks words before a key separator and names in `[section]` headers as keys.
+    fn keys(&mut self, tokens: Vec<Token>, syntax: &Syntax) -> Vec<Token> {
+        use TokenType as Ty;
+        if !syntax.has_keys() {
+            return tokens;
+        }
+        let is_word = |ty| {
+            matches!(
+                ty,
+                Ty::Str(_) | Ty::Literal | Ty::Keyword | Ty::Type | Ty::Special
+            )
+        };
+        let mut keys = vec![];
+        for mut token in tokens {
+            let line_start = !mem::replace(&mut self.line_started, true);
+            let pseudo = mem::take(&mut self.pseudo);
+            match token.ty {
+                Ty::Whitespace('\n') => {
+                    self.line_started = false;
+                    self.section = false;
+                    self.recipe = false;
+                }
+                Ty::Whitespace('\t') if line_start && syntax.has_rule(Rule::Recipes) => {
+                    self.recipe = true;
+                }
+                Ty::Whitespace(_) | Ty::Comment(_) => self.line_started = !line_start,
+                Ty::Punctuation('[') if line_start && syntax.has_rule(Rule::Sections) => {
+                    self.section = true;
+                }
+                // `==` is a comparison
+                Ty::Punctuation(p)
+                    if syntax.has_rule(Rule::Key(p))
+                        && !self.recipe
+                        && !(p == '=' && self.buffer.starts_with('=')) =>
+                {
+                    self.pending
+                        .iter_mut()
+                        .filter(|t| is_word(t.ty))
+                        .for_each(|t| t.ty = Ty::Key);
+                }
+                Ty::Keyword
+                    if syntax.has_rule(Rule::Selectors) && token.buffer.starts_with('@') =>
+                {
+                    self.grouping = true;
+                }
+                Ty::Punctuation('{') if syntax.has_rule(Rule::Selectors) => {
+                    let grouping = mem::take(&mut self.grouping);
+                    self.depth += usize::from(!grouping);
+                }
+                Ty::Punctuation(';') if syntax.has_rule(Rule::Selectors) => self.grouping = false,
+                Ty::Punctuation('}') if syntax.has_rule(Rule::Selectors) => {
+                    self.depth = self.depth.saturating_sub(1);
+                }
+                Ty::Punctuation('(') if syntax.has_rule(Rule::Selectors) => self.parens += 1,
+                Ty::Punctuation(')') if syntax.has_rule(Rule::Selectors) => {
+                    self.parens = self.parens.saturating_sub(1);
+                }
+                // Media feature, as in `@media (max-width: 10px)`
+                Ty::Punctuation(':')
+                    if syntax.has_rule(Rule::Selectors) && self.grouping && self.parens > 0 =>
+                {
+                    self.pending
+                        .iter_mut()
+                        .filter(|t| is_word(t.ty))
+                        .for_each(|t| t.ty = Ty::Key);
+                }
+                Ty::Punctuation(':') if syntax.has_rule(Rule::Selectors) && self.depth > 0 => {
+                    self.pending
+                        .iter_mut()
+                        .filter(|t| is_word(t.ty))
+                        .for_each(|t| t.ty = Ty::Attribute);
+                }
+                // Pseudo-class or pseudo-element, as in `a:hover` or `p::before`
+                Ty::Punctuation(':') if syntax.has_rule(Rule::Selectors) => {
+                    token.ty = Ty::Special;
+                    self.pseudo = true;
+                }
+                ty if syntax.has_rule(Rule::Selectors) && pseudo && is_word(ty) => {
+                    token.ty = Ty::Special;
+                }
+                Ty::Literal | Ty::Type
+                    if syntax.has_rule(Rule::Selectors) && self.depth == 0 && !self.grouping =>
+                {
+                    token.ty = Ty::Tag;
+                }
+                ty if self.section && is_word(ty) => token.ty = Ty::Key,
+                _ => (),
+            }
+            // Words, possibly dotted or dashed, are held until it's clear if a separator follows.
+            let word = is_word(token.ty);
+            let dotted = self
+                .pending
+                .iter()
+                .rfind(|t| !matches!(t.ty, Ty::Whitespace(_)))
+                .is_none_or(|t| matches!(t.ty, Ty::Punctuation('.' | '-')));
+            let glue = matches!(
+                token.ty,
+                Ty::Whitespace(' ' | '\t') | Ty::Punctuation('.' | '-' | '+' | '?')
+            );
+            if word && dotted || glue && !self.pending.is_empty() {
+                self.pending.push(token);
+            } else {
+                keys.append(&mut self.pending);
+                if word {
+                    self.pending.push(token);
+                } else {
+                    keys.push(token);
+                }
             }
         }
-        editor.append(&mut job, self);
-        job
+        keys
     }
 
-    /// Lexer
-    pub fn tokens(&mut self, syntax: &Syntax, text: &str) -> Vec<Self> {
-        let mut tokens: Vec<Self> = text
-            .chars()
-            .flat_map(|c| self.automata(c, syntax))
-            .collect();
-
-        if !self.buffer.is_empty() {
-            tokens.push(mem::take(self));
+    // Every field is a separate `Column`, quoted fields may contain separators and new lines.
+    fn columns(&mut self, c: char, separator: char) -> Vec<Token> {
+        let column = match self.ty {
+            TokenType::Column(column) => column,
+            _ => 0,
+        };
+        let quoted = self.buffer.matches('"').count() % 2 == 1;
+        let mut tokens = vec![];
+        match c {
+            c if c == separator && !quoted => {
+                tokens.extend(self.drain(TokenType::Punctuation(c)));
+                tokens.extend(self.push_drain(c, TokenType::Column(column + 1)));
+            }
+            '\n' if !quoted => {
+                tokens.extend(self.drain(TokenType::Whitespace(c)));
+                tokens.extend(self.push_drain(c, TokenType::Column(0)));
+            }
+            _ => {
+                self.buffer.push(c);
+                self.ty = TokenType::Column(column);
+            }
         }
         tokens
     }
 
-    fn automata(&mut self, c: char, syntax: &Syntax) -> Vec<Self> {
+    fn automata(&mut self, c: char, syntax: &Syntax) -> Vec<Token> {
+        if let Some(separator) = syntax.column_separator() {
+            return self.columns(c, separator);
+        }
+        let tokens = self.lex(c, syntax);
+        self.keys(tokens, syntax)
+    }
+
+    fn lex(&mut self, c: char, syntax: &Syntax) -> Vec<Token> {
         use TokenType as Ty;
         let mut tokens = vec![];
         match (self.ty, Ty::from(c)) {
-            (Ty::Comment(false), Ty::Whitespace('\n')) => {
+            (Ty::Comment(false) | Ty::Heading | Ty::Emphasis, Ty::Whitespace('\n')) => {
+                if self.ty == Ty::Emphasis {
+                    // Never closed
+                    self.ty = Ty::Literal;
+                }
                 self.buffer.push(c);
                 let n = self.buffer.pop();
                 tokens.extend(self.drain(Ty::Whitespace(c)));
@@ -107,8 +417,41 @@ impl Token {
                     tokens.extend(self.push_drain(n, self.ty));
                 }
             }
+            (Ty::Comment(false), Ty::Punctuation('['))
+                if self.buffer.len() == 1
+                    && self
+                        .buffer
+                        .starts_with(|p| syntax.has_rule(Rule::Comment(p))) =>
+            {
+                // Not a comment, e.g. attribute `#[Pure]`
+                self.ty = Ty::Punctuation(self.buffer.chars().next().unwrap_or(c));
+                tokens.extend(self.lex(c, syntax));
+            }
             (Ty::Comment(false), _) => {
                 self.buffer.push(c);
+                // Longer multiline opener, like `#=` after `#`
+                if self.buffer == syntax.comment_multiline[0] {
+                    self.ty = Ty::Comment(true);
+                }
+            }
+            (Ty::Heading, _) => {
+                self.buffer.push(c);
+            }
+            (Ty::Emphasis, _) => {
+                self.buffer.push(c);
+                if self.emphasis_closed() {
+                    tokens.extend(self.drain(Ty::Unknown));
+                }
+            }
+            (Ty::Punctuation(m @ ('*' | '_')), _)
+                if syntax.has_rule(Rule::Emphasis)
+                    && !c.is_whitespace()
+                    && self.buffer.chars().all(|b| b == m) =>
+            {
+                self.buffer.push(c);
+                if c != m {
+                    self.ty = Ty::Emphasis;
+                }
             }
             (Ty::Comment(true), _) => {
                 self.buffer.push(c);
@@ -124,11 +467,16 @@ impl Token {
                 tokens.extend(self.drain(Ty::Whitespace(c)));
                 tokens.extend(self.first(c, syntax));
             }
+            (Ty::Hyperlink, Ty::Punctuation(')')) if syntax.has_rule(Rule::Prose) => {
+                tokens.extend(self.drain(Ty::Punctuation(c)));
+                tokens.extend(self.first(c, syntax));
+            }
             (Ty::Hyperlink, _) => {
                 self.buffer.push(c);
             }
             (Ty::Literal, _) => match c {
-                c if c == '(' => {
+                '\'' if syntax.has_rule(Rule::Primes) => self.buffer.push(c),
+                c if c == '(' && !syntax.has_rule(Rule::Prose) => {
                     self.ty = Ty::Function;
                     tokens.extend(self.drain(Ty::Punctuation(c)));
                     tokens.extend(self.push_drain(c, Ty::Unknown));
@@ -136,18 +484,26 @@ impl Token {
                 c if !c.is_alphanumeric() && !SEPARATORS.contains(&c) => {
                     tokens.extend(self.drain(self.ty));
                     self.buffer.push(c);
-                    self.ty = if QUOTES.contains(&c) {
+                    self.ty = if QUOTES.contains(&c)
+                        && self.opens_str(c, syntax)
+                        && !(c == '\'' && syntax.has_rule(Rule::Apostrophes))
+                    {
                         Ty::Str(c)
+                    } else if syntax.has_rule(Rule::Comment(c)) {
+                        Ty::Comment(false)
                     } else {
                         Ty::Punctuation(c)
                     };
+                    self.open_tag(c, syntax);
                 }
                 _ => {
                     self.buffer.push(c);
                     self.ty = {
-                        if self.buffer.starts_with(syntax.comment) {
+                        if !syntax.comment.is_empty() && self.buffer.starts_with(syntax.comment) {
                             Ty::Comment(false)
-                        } else if self.buffer.starts_with(syntax.comment_multiline[0]) {
+                        } else if !syntax.comment_multiline[0].is_empty()
+                            && self.buffer.starts_with(syntax.comment_multiline[0])
+                        {
                             Ty::Comment(true)
                         } else if syntax.is_hyperlink(&self.buffer) {
                             Ty::Hyperlink
@@ -155,7 +511,9 @@ impl Token {
                             Ty::Keyword
                         } else if syntax.is_type(&self.buffer) {
                             Ty::Type
-                        } else if syntax.is_special(&self.buffer) {
+                        } else if syntax.is_special(&self.buffer)
+                            || syntax.is_prefixed(&self.buffer)
+                        {
                             Ty::Special
                         } else {
                             Ty::Literal
@@ -171,14 +529,79 @@ impl Token {
                 self.buffer.push(c);
             }
             (Ty::Numeric(_), Ty::Literal) => {
-                tokens.extend(self.drain(self.ty));
+                // Prefixes and suffixes like `0xFF` or `10UL` are part of the number,
+                // suffixes after `_` as in `3.14_f32` are not
+                if c == '_' || self.buffer.ends_with('_') {
+                    tokens.extend(self.drain(self.ty));
+                }
+                self.buffer.push(c);
+            }
+            (Ty::Punctuation(p), Ty::Punctuation(q))
+                if p == q && self.buffer.len() == 1 && syntax.has_rule(Rule::LineStrings(p)) =>
+            {
+                self.buffer.push(c);
+                self.ty = Ty::Str('\n');
+            }
+            (Ty::Punctuation('/'), _) if self.regex_start(c, syntax) => {
+                self.buffer.push(c);
+                self.ty = Ty::Str('/');
+            }
+            (Ty::Special, _) if self.sigil_close(c, syntax).is_some() => {
+                let close = self.sigil_close(c, syntax).unwrap_or(c);
+                self.buffer.push(c);
+                self.ty = Ty::Str(close);
+            }
+            (Ty::Special, _) if self.variable_close(syntax).is_some() => {
+                if c == '\n' {
+                    tokens.extend(self.drain(Ty::Whitespace(c)));
+                    tokens.extend(self.first(c, syntax));
+                } else {
+                    self.buffer.push(c);
+                    let close = self.variable_close(syntax).unwrap_or(c);
+                    let open = self.buffer.chars().nth(1).unwrap_or(c);
+                    if self.buffer.matches(close).count() >= self.buffer.matches(open).count() {
+                        tokens.extend(self.drain(Ty::Unknown));
+                    }
+                }
+            }
+            (Ty::Punctuation(p), Ty::Punctuation(_))
+                if self.buffer.len() == p.len_utf8() && syntax.has_rule(Rule::Variables(p)) =>
+            {
+                if matches!(c, '(' | '{') {
+                    self.buffer.push(c);
+                    self.ty = Ty::Special;
+                } else if matches!(c, '@' | '<' | '^' | '?' | '*' | '%' | '+' | '|' | '#' | '$') {
+                    // Automatic variables like `$@`
+                    self.ty = Ty::Special;
+                    tokens.extend(self.push_drain(c, Ty::Unknown));
+                } else {
+                    tokens.extend(self.drain(self.ty));
+                    tokens.extend(self.first(c, syntax));
+                }
+            }
+            (Ty::Punctuation(_), Ty::Literal)
+                if !self.midline
+                    && syntax.comment_multiline[0].starts_with(&format!("{}{c}", self.buffer)) =>
+            {
+                // Multiline opener starting a line, like `=begin`
+                self.buffer.push(c);
+                if self.buffer == syntax.comment_multiline[0] {
+                    self.ty = Ty::Comment(true);
+                }
+            }
+            (Ty::Punctuation(p), Ty::Literal)
+                if self.buffer.len() == p.len_utf8()
+                    && syntax.has_prefix(p)
+                    && (!syntax.has_rule(Rule::Sigils(p)) || self.expression_start()) =>
+            {
                 self.buffer.push(c);
+                self.ty = Ty::Special;
             }
             (Ty::Numeric(_), _) | (Ty::Punctuation(_), Ty::Literal | Ty::Numeric(_)) => {
                 tokens.extend(self.drain(self.ty));
                 tokens.extend(self.first(c, syntax));
             }
-            (Ty::Punctuation(_), Ty::Str(_)) => {
+            (Ty::Punctuation(_), Ty::Str(_)) if self.opens_str(c, syntax) => {
                 tokens.extend(self.drain_push(c, Ty::Str(c)));
             }
             (Ty::Punctuation(_), _) => {
@@ -189,20 +612,66 @@ impl Token {
                     tokens.extend(self.first(c, syntax));
                 } else {
                     self.buffer.push(c);
-                    if self.buffer.starts_with(syntax.comment) {
+                    if !syntax.comment.is_empty() && self.buffer.starts_with(syntax.comment) {
                         self.ty = Ty::Comment(false);
-                    } else if self.buffer.starts_with(syntax.comment_multiline[0]) {
+                    } else if !syntax.comment_multiline[0].is_empty()
+                        && self.buffer.starts_with(syntax.comment_multiline[0])
+                    {
                         self.ty = Ty::Comment(true);
+                    } else if syntax.comment_multiline[0].starts_with(&self.buffer) {
+                        // Longer openers like `<!--` are still incomplete
                     } else if let Some(c) = self.buffer.pop() {
                         tokens.extend(self.drain(Ty::Punctuation(c)));
                         tokens.extend(self.first(c, syntax));
                     }
                 }
             }
+            (Ty::Str('\n'), Ty::Whitespace('\n')) => {
+                tokens.extend(self.drain(Ty::Whitespace(c)));
+                tokens.extend(self.first(c, syntax));
+            }
+            (Ty::Str('\''), _)
+                if syntax.has_rule(Rule::CharLiterals)
+                    && self.buffer.chars().count() == 2
+                    && !self.buffer.ends_with('\\')
+                    && c != '\'' =>
+            {
+                // Not a char, e.g. type variable `'a`
+                self.ty = Ty::Special;
+                tokens.extend(self.lex(c, syntax));
+            }
+            (Ty::Str('/'), _) if self.regex_closed() => {
+                if c.is_alphabetic() {
+                    self.buffer.push(c);
+                } else {
+                    tokens.extend(self.drain(Ty::Unknown));
+                    tokens.extend(self.first(c, syntax));
+                }
+            }
+            (Ty::Str(q), _) if syntax.has_rule(Rule::TripleQuotes) && self.buffer.len() < 3 => {
+                // `""` is either an empty string or the opening of `"""`
+                if self.buffer.len() == 2 && self.buffer.ends_with(q) && c != q {
+                    tokens.extend(self.drain(Ty::Unknown));
+                    tokens.extend(self.first(c, syntax));
+                } else {
+                    let control = self.buffer.ends_with('\\');
+                    self.buffer.push(c);
+                    if c == q && !control && self.buffer.len() == 3 && !self.triple_quoted(q) {
+                        tokens.extend(self.drain(Ty::Unknown));
+                    }
+                }
+            }
+            (Ty::Str(q), _) if self.triple_quoted(q) => {
+                self.buffer.push(c);
+                if self.buffer.len() >= 6 && self.buffer.ends_with(&q.to_string().repeat(3)) {
+                    tokens.extend(self.drain(Ty::Unknown));
+                }
+            }
             (Ty::Str(q), _) => {
                 let control = self.buffer.ends_with('\\');
                 self.buffer.push(c);
-                if c == q && !control {
+                // Regex flags may follow
+                if c == q && !control && q != '/' {
                     tokens.extend(self.drain(Ty::Unknown));
                 }
             }
@@ -216,7 +685,7 @@ impl Token {
                     Ty::Keyword
                 } else if syntax.is_type(&self.buffer) {
                     Ty::Type
-                } else if syntax.is_special(&self.buffer) {
+                } else if syntax.is_special(&self.buffer) || syntax.is_prefixed(&self.buffer) {
                     Ty::Special
                 } else {
                     Ty::Literal
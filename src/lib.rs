@@ -34,14 +34,20 @@
 //!
 //! fn color(token: TokenType) -> Color {
 //!     match token {
+//!         TokenType::Attribute => Color::LightSalmon1,
+//!         TokenType::Column(_) => Color::SkyBlue1,
 //!         TokenType::Comment(_) => Color::Grey37,
+//!         TokenType::Emphasis => Color::Plum1,
 //!         TokenType::Function => Color::Yellow3b,
+//!         TokenType::Heading => Color::DeepSkyBlue1,
+//!         TokenType::Key => Color::LightGoldenrod1,
 //!         TokenType::Keyword => Color::IndianRed1c,
 //!         TokenType::Literal => Color::NavajoWhite1,
 //!         TokenType::Numeric(_) => Color::MediumPurple,
 //!         TokenType::Punctuation(_) => Color::Orange3,
 //!         TokenType::Special => Color::Cyan,
 //!         TokenType::Str(_) => Color::Green,
+//!         TokenType::Tag => Color::LightCoral,
 //!         TokenType::Type => Color::GreenYellow,
 //!         TokenType::Whitespace(_) => Color::White,
 //!         TokenType::Unknown => Color::Pink1,
@@ -54,7 +60,7 @@
 //!     .id_source("code editor")
 //!     .with_rows(12)
 //!     .with_fontsize(14.0)
-//!     .with_theme(self.theme)
+//!     .with_theme(self.theme.to_owned())
 //!     .with_syntax(self.syntax.to_owned())
 //!     .with_numlines(true)
 //!     .vscroll(true)
@@ -70,6 +76,7 @@
 #[cfg(feature = "egui")]
 mod completer;
 pub mod highlighting;
+pub mod render;
 mod syntax;
 #[cfg(test)]
 mod tests;
@@ -82,11 +89,21 @@ use egui::widgets::text_edit::TextEditOutput;
 pub use highlighting::Token;
 #[cfg(feature = "egui")]
 use highlighting::highlight;
+#[cfg(feature = "ratatui")]
+pub use render::CodeView;
+pub use render::{
+    AnsiRenderer, ColorDepth, HtmlRenderer, LatexRenderer, RichCopy, RtfRenderer, SvgRenderer,
+};
 #[cfg(feature = "editor")]
 use std::hash::{Hash, Hasher};
-pub use syntax::{Syntax, TokenType};
-pub use themes::ColorTheme;
+pub use syntax::{Rule, Syntax, TokenType};
 pub use themes::DEFAULT_THEMES;
+pub use themes::{
+    ChromeColors, ColorTheme, FontStyle, HexColor, ImportError, ThemeError, ThemeStyles,
+};
+#[cfg(feature = "editor")]
+#[cfg(feature = "egui")]
+pub use themes::{ThemeEditor, ThemeExport};
 
 #[cfg(feature = "egui")]
 pub use crate::completer::Completer;
@@ -114,6 +131,7 @@ pub struct CodeEditor {
     desired_width: f32,
     readonly: bool,
     wrap_lines: bool,
+    rich_copy: bool,
 }
 
 #[cfg(feature = "editor")]
@@ -145,6 +163,7 @@ impl Default for CodeEditor {
             desired_width: f32::INFINITY,
             readonly: false,
             wrap_lines: false,
+            rich_copy: false,
         }
     }
 }
@@ -280,6 +299,22 @@ impl CodeEditor {
         CodeEditor { wrap_lines, ..self }
     }
 
+    /// Hand copied selections to the app as highlighted HTML and RTF
+    ///
+    /// Opt-in for apps with their own clipboard: egui copies only plain text, the app fetches
+    /// the highlighted copy with `take_rich_copy` and places it on the clipboard itself.
+    /// Adds "Copy" to the context menu, copying the same way as the keyboard shortcut.
+    /// **Default: false**
+    pub fn with_rich_copy(self, rich_copy: bool) -> Self {
+        CodeEditor { rich_copy, ..self }
+    }
+
+    #[cfg(feature = "egui")]
+    /// Selection copied since the last call, for a clipboard supporting HTML or RTF
+    pub fn take_rich_copy(&self, ctx: &egui::Context) -> Option<RichCopy> {
+        ctx.data_mut(|d| d.remove_temp(self.rich_copy_id()))
+    }
+
     #[cfg(feature = "egui")]
     pub fn format_token(&self, ty: TokenType) -> egui::text::TextFormat {
         format_token(&self.theme, self.fontsize, ty)
@@ -321,27 +356,82 @@ impl CodeEditor {
             * 0.5
             * !(total + self.numlines_shift <= 0 && self.numlines_only_natural) as u8 as f32;
 
+        let chrome = self.theme.chrome_colors();
+        let active_row = ui.data(|d| d.get_temp::<usize>(self.cursor_row_id()));
         let mut layouter = |ui: &egui::Ui, text_buffer: &dyn TextBuffer, _wrap_width: f32| {
-            let layout_job = egui::text::LayoutJob::single_section(
-                text_buffer.as_str().to_string(),
-                egui::TextFormat::simple(
-                    egui::FontId::monospace(self.fontsize),
-                    self.theme.type_color(TokenType::Comment(true)),
-                ),
-            );
+            let mut layout_job = egui::text::LayoutJob::default();
+            for (row, line) in text_buffer.as_str().split_inclusive('\n').enumerate() {
+                let color = if active_row == Some(row) {
+                    chrome.active_line_number
+                } else {
+                    chrome.line_numbers
+                };
+                layout_job.append(
+                    line,
+                    0.0,
+                    egui::TextFormat::simple(egui::FontId::monospace(self.fontsize), color.into()),
+                );
+            }
             ui.fonts_mut(|f| f.layout_job(layout_job))
         };
 
-        ui.add(
-            egui::TextEdit::multiline(&mut counter)
-                .id_source(format!("{}_numlines", self.id))
-                .font(egui::TextStyle::Monospace)
-                .interactive(false)
-                .frame(false)
-                .desired_rows(self.rows)
-                .desired_width(width)
-                .layouter(&mut layouter),
-        );
+        egui::Frame::NONE.fill(chrome.gutter.into()).show(ui, |ui| {
+            ui.add(
+                egui::TextEdit::multiline(&mut counter)
+                    .id_source(format!("{}_numlines", self.id))
+                    .font(egui::TextStyle::Monospace)
+                    .interactive(false)
+                    .frame(false)
+                    .desired_rows(self.rows)
+                    .desired_width(width)
+                    .layouter(&mut layouter),
+            );
+        });
+    }
+
+    #[cfg(feature = "egui")]
+    // Row of the cursor from previous frame, for active line number.
+    fn cursor_row_id(&self) -> egui::Id {
+        egui::Id::new(&self.id).with("cursor_row")
+    }
+
+    #[cfg(feature = "egui")]
+    fn rich_copy_id(&self) -> egui::Id {
+        egui::Id::new(&self.id).with("rich_copy")
+    }
+
+    #[cfg(feature = "egui")]
+    // Stores the selection on copy or cut, from the keyboard or the context menu.
+    fn rich_copy_show(&self, ui: &egui::Ui, text: &str, output: &TextEditOutput) {
+        let selected: String = output
+            .state
+            .cursor
+            .char_range()
+            .map(|range| {
+                let range = range.as_sorted_char_range();
+                text.chars().skip(range.start).take(range.len()).collect()
+            })
+            .unwrap_or_default();
+        let store = |ctx: &egui::Context, selected: &str| {
+            let copy = RichCopy::new(&self.theme, &self.syntax, selected);
+            ctx.data_mut(|d| d.insert_temp(self.rich_copy_id(), copy));
+        };
+        let copied = ui.input(|i| {
+            i.events
+                .iter()
+                .any(|e| matches!(e, egui::Event::Copy | egui::Event::Cut))
+        });
+        if copied && output.response.has_focus() && !selected.is_empty() {
+            store(ui.ctx(), &selected);
+        }
+        output.response.context_menu(|ui| {
+            let enabled = !selected.is_empty();
+            if ui.add_enabled(enabled, egui::Button::new("Copy")).clicked() {
+                ui.ctx().copy_text(selected.clone());
+                store(ui.ctx(), &selected);
+                ui.close();
+            }
+        });
     }
 
     #[cfg(feature = "egui")]
@@ -396,6 +486,18 @@ impl CodeEditor {
                             .layouter(&mut layouter)
                             .readonly(self.readonly)
                             .show(ui);
+                        if let Some(range) = output.cursor_range {
+                            let row = text
+                                .as_str()
+                                .chars()
+                                .take(range.primary.index)
+                                .filter(|&c| c == '\n')
+                                .count();
+                            ui.data_mut(|d| d.insert_temp(self.cursor_row_id(), row));
+                        }
+                        if self.rich_copy {
+                            self.rich_copy_show(ui, text.as_str(), &output);
+                        }
                         text_edit_output = Some(output);
                     });
             });
@@ -431,5 +533,20 @@ impl Editor for CodeEditor {
 pub fn format_token(theme: &ColorTheme, fontsize: f32, ty: TokenType) -> egui::text::TextFormat {
     let font_id = egui::FontId::monospace(fontsize);
     let color = theme.type_color(ty);
-    egui::text::TextFormat::simple(font_id, color)
+    let style = theme.type_style(ty);
+    let stroke = |on: bool| {
+        if on {
+            egui::Stroke::new(fontsize * 0.06, color)
+        } else {
+            egui::Stroke::NONE
+        }
+    };
+    // `bold` is left out, egui has no bold weight of the monospace font
+    egui::text::TextFormat {
+        italics: style.italic,
+        underline: stroke(style.underline),
+        strikethrough: stroke(style.strikethrough),
+        background: theme.type_background(ty),
+        ..egui::text::TextFormat::simple(font_id, color)
+    }
 }
@@ -0,0 +1,259 @@
+use super::{line_labels, lines};
+use crate::syntax::{Syntax, TokenType};
+use crate::themes::{ColorTheme, HexColor};
+use std::env;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+// xterm default palette of the 16 basic colors.
+const BASIC: [u32; 16] = [
+    0x000000, 0xcd0000, 0x00cd00, 0xcdcd00, 0x0000ee, 0xcd00cd, 0x00cdcd, 0xe5e5e5, 0x7f7f7f,
+    0xff0000, 0x00ff00, 0xffff00, 0x5c5cff, 0xff00ff, 0x00ffff, 0xffffff,
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Colors supported by the terminal.
+pub enum ColorDepth {
+    /// No escape sequences at all.
+    Plain,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Guess from `NO_COLOR`, `COLORTERM` and `TERM` environment variables.
+    pub fn detect() -> Self {
+        let var = |name| env::var(name).unwrap_or_default();
+        if env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) || var("TERM") == "dumb" {
+            ColorDepth::Plain
+        } else if matches!(var("COLORTERM").as_str(), "truecolor" | "24bit") {
+            ColorDepth::TrueColor
+        } else if var("TERM").contains("256color") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+
+    // Parameters of SGR escape sequence, `fg` or background.
+    fn sgr(self, color: HexColor, fg: bool) -> String {
+        let HexColor { r, g, b, .. } = color;
+        match self {
+            ColorDepth::Plain => String::new(),
+            ColorDepth::TrueColor => format!("{};2;{r};{g};{b}", if fg { 38 } else { 48 }),
+            ColorDepth::Ansi256 => format!("{};5;{}", if fg { 38 } else { 48 }, ansi256(color)),
+            ColorDepth::Ansi16 => {
+                let i = ansi16(color);
+                let base = match (fg, i < 8) {
+                    (true, true) => 30,
+                    (true, false) => 82,
+                    (false, true) => 40,
+                    (false, false) => 92,
+                };
+                (base + i).to_string()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Renders highlighted code with ANSI escape sequences for terminals.
+pub struct AnsiRenderer {
+    theme: ColorTheme,
+    syntax: Syntax,
+    depth: ColorDepth,
+    background: bool,
+    numlines: bool,
+    numlines_shift: isize,
+    numlines_only_natural: bool,
+    range: Option<RangeInclusive<isize>>,
+    gutter: bool,
+}
+
+impl Default for AnsiRenderer {
+    fn default() -> Self {
+        AnsiRenderer {
+            theme: ColorTheme::GRUVBOX,
+            syntax: Syntax::rust(),
+            depth: ColorDepth::detect(),
+            background: false,
+            numlines: false,
+            numlines_shift: 0,
+            numlines_only_natural: false,
+            range: None,
+            gutter: false,
+        }
+    }
+}
+
+impl AnsiRenderer {
+    pub fn with_theme(self, theme: ColorTheme) -> Self {
+        AnsiRenderer { theme, ..self }
+    }
+
+    pub fn with_syntax(self, syntax: Syntax) -> Self {
+        AnsiRenderer { syntax, ..self }
+    }
+
+    /// Detected from environment by default.
+    pub fn with_depth(self, depth: ColorDepth) -> Self {
+        AnsiRenderer { depth, ..self }
+    }
+
+    /// Paint theme background up to the end of every line.
+    pub fn with_background(self, background: bool) -> Self {
+        AnsiRenderer { background, ..self }
+    }
+
+    pub fn with_numlines(self, numlines: bool) -> Self {
+        AnsiRenderer { numlines, ..self }
+    }
+
+    pub fn with_numlines_shift(self, numlines_shift: isize) -> Self {
+        AnsiRenderer {
+            numlines_shift,
+            ..self
+        }
+    }
+
+    pub fn with_numlines_only_natural(self, numlines_only_natural: bool) -> Self {
+        AnsiRenderer {
+            numlines_only_natural,
+            ..self
+        }
+    }
+
+    /// Render only lines with these shown numbers, lexing the whole code for multiline tokens.
+    pub fn with_line_range(self, range: RangeInclusive<isize>) -> Self {
+        AnsiRenderer {
+            range: Some(range),
+            ..self
+        }
+    }
+
+    /// Separate line numbers from code with `│` on the gutter background.
+    pub fn with_gutter(self, gutter: bool) -> Self {
+        AnsiRenderer { gutter, ..self }
+    }
+
+    pub fn render(&self, code: &str) -> String {
+        let theme = &self.theme;
+        let chrome = theme.chrome_colors();
+        let plain = self.depth == ColorDepth::Plain;
+        let bg = if self.background {
+            format!(";{}", self.depth.sgr(theme.bg, false))
+        } else {
+            String::new()
+        };
+        let mut ansi = String::new();
+        let lines = lines(&self.syntax, code);
+        let labels = line_labels(lines.len(), self.numlines_shift, self.numlines_only_natural);
+        for (i, (tokens, label)) in lines.iter().zip(labels).enumerate() {
+            let num = i as isize + 1 + self.numlines_shift;
+            if self
+                .range
+                .as_ref()
+                .is_some_and(|range| !range.contains(&num))
+            {
+                continue;
+            }
+            if self.numlines || self.gutter {
+                let mut gutter = String::new();
+                if self.numlines {
+                    gutter.push_str(&label);
+                }
+                if self.gutter {
+                    gutter.push_str(" │");
+                }
+                if plain {
+                    ansi.push_str(&gutter);
+                } else {
+                    let _ = write!(
+                        ansi,
+                        "\x1b[0;{};{}m{gutter}",
+                        self.depth.sgr(chrome.line_numbers, true),
+                        self.depth.sgr(chrome.gutter, false)
+                    );
+                }
+                ansi.push(' ');
+            }
+            if !plain && (self.numlines || self.gutter || self.background) {
+                let _ = write!(ansi, "\x1b[0{bg}m");
+            }
+            for token in tokens {
+                let ty = token.ty();
+                if plain || matches!(ty, TokenType::Whitespace(_)) {
+                    ansi.push_str(token.buffer());
+                    continue;
+                }
+                let style = theme.type_style(ty);
+                let mut sgr = format!("0;{}{bg}", self.depth.sgr(theme.type_hex_color(ty), true));
+                for (on, code) in [
+                    (style.bold, ";1"),
+                    (style.italic, ";3"),
+                    (style.underline, ";4"),
+                    (style.strikethrough, ";9"),
+                ] {
+                    if on {
+                        sgr.push_str(code);
+                    }
+                }
+                if let Some(background) = style.background {
+                    let _ = write!(sgr, ";{}", self.depth.sgr(background, false));
+                }
+                let _ = write!(ansi, "\x1b[{sgr}m{}", token.buffer());
+            }
+            if !plain && self.background {
+                // Erasing the rest of line fills it with background
+                let _ = write!(ansi, "\x1b[0{bg}m\x1b[K");
+            }
+            if !plain {
+                ansi.push_str("\x1b[0m");
+            }
+            ansi.push('\n');
+        }
+        ansi
+    }
+}
+
+fn distance(color: HexColor, rgb: u32) -> u32 {
+    let [_, r, g, b] = rgb.to_be_bytes();
+    [(color.r, r), (color.g, g), (color.b, b)]
+        .into_iter()
+        .map(|(a, b)| u32::from(a.abs_diff(b)).pow(2))
+        .sum()
+}
+
+fn ansi16(color: HexColor) -> u8 {
+    (0..16u8)
+        .min_by_key(|&i| distance(color, BASIC[i as usize]))
+        .unwrap_or_default()
+}
+
+// Nearest of the 6×6×6 color cube and the 24 grays.
+fn ansi256(color: HexColor) -> u8 {
+    let level = |v: u8| match v {
+        0..48 => 0,
+        48..115 => 1,
+        _ => (v - 35) / 40,
+    };
+    let value = |level: u8| {
+        if level == 0 {
+            0
+        } else {
+            55 + 40 * u32::from(level)
+        }
+    };
+    let (r, g, b) = (level(color.r), level(color.g), level(color.b));
+    let cube = value(r) << 16 | value(g) << 8 | value(b);
+    let average = (u32::from(color.r) + u32::from(color.g) + u32::from(color.b)) / 3;
+    let gray_level = (average.saturating_sub(3) / 10).min(23);
+    let gray_value = 8 + 10 * gray_level;
+    let gray = gray_value << 16 | gray_value << 8 | gray_value;
+    if distance(color, gray) < distance(color, cube) {
+        232 + gray_level as u8
+    } else {
+        16 + 36 * r + 6 * g + b
+    }
+}
@@ -0,0 +1,30 @@
+use super::{HtmlRenderer, RtfRenderer};
+use crate::syntax::Syntax;
+use crate::themes::ColorTheme;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+/// Highlighted code in clipboard formats, rendered with `HtmlRenderer` and `RtfRenderer`.
+///
+/// egui puts only plain text on the clipboard, so pass these to a platform clipboard,
+/// as in `arboard::Clipboard::set_html(copy.html, Some(copy.text))`.
+pub struct RichCopy {
+    pub text: String,
+    pub html: String,
+    pub rtf: String,
+}
+
+impl RichCopy {
+    pub fn new(theme: &ColorTheme, syntax: &Syntax, text: &str) -> Self {
+        RichCopy {
+            text: text.to_string(),
+            html: HtmlRenderer::default()
+                .with_theme(theme.clone())
+                .with_syntax(syntax.clone())
+                .render(text),
+            rtf: RtfRenderer::default()
+                .with_theme(theme.clone())
+                .with_syntax(syntax.clone())
+                .render(text),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use super::{escape_xml, line_labels, lines};
+use crate::syntax::{Syntax, TokenType};
+use crate::themes::{ColorTheme, css_color, css_declarations};
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Renders highlighted code as an HTML `<pre class="code">` block.
+///
+/// Styles are inline by default. With classes, tokens get `tok-*` classes styled by
+/// `ColorTheme::to_css("pre.code")`.
+pub struct HtmlRenderer {
+    theme: ColorTheme,
+    syntax: Syntax,
+    classes: bool,
+    numlines: bool,
+    numlines_shift: isize,
+    numlines_only_natural: bool,
+    range: Option<RangeInclusive<isize>>,
+    anchors: Option<String>,
+    highlighted: Vec<RangeInclusive<isize>>,
+}
+
+impl Default for HtmlRenderer {
+    fn default() -> Self {
+        HtmlRenderer {
+            theme: ColorTheme::GRUVBOX,
+            syntax: Syntax::rust(),
+            classes: false,
+            numlines: false,
+            numlines_shift: 0,
+            numlines_only_natural: false,
+            range: None,
+            anchors: None,
+            highlighted: Vec::new(),
+        }
+    }
+}
+
+impl HtmlRenderer {
+    pub fn with_theme(self, theme: ColorTheme) -> Self {
+        HtmlRenderer { theme, ..self }
+    }
+
+    pub fn with_syntax(self, syntax: Syntax) -> Self {
+        HtmlRenderer { syntax, ..self }
+    }
+
+    /// Use `tok-*` classes instead of inline styles.
+    pub fn with_classes(self, classes: bool) -> Self {
+        HtmlRenderer { classes, ..self }
+    }
+
+    pub fn with_numlines(self, numlines: bool) -> Self {
+        HtmlRenderer { numlines, ..self }
+    }
+
+    /// Shift line numbers, anchors and highlighted ranges, as in `CodeEditor`.
+    pub fn with_numlines_shift(self, numlines_shift: isize) -> Self {
+        HtmlRenderer {
+            numlines_shift,
+            ..self
+        }
+    }
+
+    /// Hide line numbers below 1.
+    pub fn with_numlines_only_natural(self, numlines_only_natural: bool) -> Self {
+        HtmlRenderer {
+            numlines_only_natural,
+            ..self
+        }
+    }
+
+    /// Render only lines with these shown numbers, lexing the whole code for multiline tokens.
+    pub fn with_line_range(self, range: RangeInclusive<isize>) -> Self {
+        HtmlRenderer {
+            range: Some(range),
+            ..self
+        }
+    }
+
+    /// Give every line an `id` made of the prefix and its number, as in `L12`.
+    pub fn with_anchors(self, prefix: impl Into<String>) -> Self {
+        HtmlRenderer {
+            anchors: Some(prefix.into()),
+            ..self
+        }
+    }
+
+    /// Highlight lines by their shown numbers, may be called several times.
+    pub fn highlight_lines(mut self, lines: RangeInclusive<isize>) -> Self {
+        self.highlighted.push(lines);
+        self
+    }
+
+    pub fn render(&self, code: &str) -> String {
+        let theme = &self.theme;
+        let chrome = theme.chrome_colors();
+        let mut html = if self.classes {
+            String::from("<pre class=\"code\"><code>")
+        } else {
+            format!(
+                "<pre class=\"code\" style=\"color: {}; background-color: {};\"><code>",
+                css_color(theme.literals),
+                css_color(theme.bg)
+            )
+        };
+        let lines = lines(&self.syntax, code);
+        let labels = line_labels(lines.len(), self.numlines_shift, self.numlines_only_natural);
+        for (i, (tokens, label)) in lines.iter().zip(labels).enumerate() {
+            let num = i as isize + 1 + self.numlines_shift;
+            if self
+                .range
+                .as_ref()
+                .is_some_and(|range| !range.contains(&num))
+            {
+                continue;
+            }
+            let highlighted = self.highlighted.iter().any(|range| range.contains(&num));
+            let mut attributes = String::new();
+            if let Some(prefix) = &self.anchors {
+                let _ = write!(attributes, " id=\"{}{num}\"", escape_xml(prefix));
+            }
+            if highlighted && self.classes {
+                attributes.push_str(" class=\"tok-line-highlighted\"");
+            } else if highlighted {
+                let _ = write!(
+                    attributes,
+                    " style=\"display: inline-block; width: 100%; background-color: {};\"",
+                    css_color(chrome.current_line)
+                );
+            }
+            if !attributes.is_empty() {
+                let _ = write!(html, "<span{attributes}>");
+            }
+            if self.numlines && self.classes {
+                let _ = write!(html, "<span class=\"tok-line-number\">{label} </span>");
+            } else if self.numlines {
+                let _ = write!(
+                    html,
+                    "<span style=\"color: {}; user-select: none;\">{label} </span>",
+                    css_color(chrome.line_numbers)
+                );
+            }
+            for token in tokens {
+                let text = escape_xml(token.buffer());
+                match token.ty() {
+                    TokenType::Whitespace(_) => html.push_str(&text),
+                    ty if self.classes => {
+                        let _ = write!(html, "<span class=\"{}\">{text}</span>", ty.class());
+                    }
+                    ty => {
+                        let style = css_declarations(
+                            &css_color(theme.type_hex_color(ty)),
+                            theme.type_style(ty),
+                        );
+                        let _ = write!(html, "<span style=\"{style}\">{text}</span>");
+                    }
+                }
+            }
+            if !attributes.is_empty() {
+                html.push_str("</span>");
+            }
+            html.push('\n');
+        }
+        html.push_str("</code></pre>\n");
+        html
+    }
+}
@@ -0,0 +1,116 @@
+use super::lines;
+use crate::syntax::{Syntax, TokenType};
+use crate::themes::{ColorTheme, HexColor};
+use std::fmt::Write;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Renders highlighted code as a `fancyvrb` `Verbatim` environment colored with `\textcolor`.
+///
+/// Needs `\usepackage{xcolor}` and `\usepackage{fancyvrb}`, and `\usepackage{ulem}` for
+/// strikethrough.
+pub struct LatexRenderer {
+    theme: ColorTheme,
+    syntax: Syntax,
+    numlines: bool,
+    numlines_shift: isize,
+}
+
+impl Default for LatexRenderer {
+    fn default() -> Self {
+        LatexRenderer {
+            theme: ColorTheme::GITHUB_LIGHT,
+            syntax: Syntax::rust(),
+            numlines: false,
+            numlines_shift: 0,
+        }
+    }
+}
+
+impl LatexRenderer {
+    pub fn with_theme(self, theme: ColorTheme) -> Self {
+        LatexRenderer { theme, ..self }
+    }
+
+    pub fn with_syntax(self, syntax: Syntax) -> Self {
+        LatexRenderer { syntax, ..self }
+    }
+
+    pub fn with_numlines(self, numlines: bool) -> Self {
+        LatexRenderer { numlines, ..self }
+    }
+
+    pub fn with_numlines_shift(self, numlines_shift: isize) -> Self {
+        LatexRenderer {
+            numlines_shift,
+            ..self
+        }
+    }
+
+    pub fn render(&self, code: &str) -> String {
+        let theme = &self.theme;
+        let mut options = format!(
+            "commandchars=\\\\\\{{\\}},formatcom=\\color[HTML]{{{}}}",
+            html(theme.literals)
+        );
+        if self.numlines {
+            let _ = write!(
+                options,
+                ",numbers=left,firstnumber={}",
+                1 + self.numlines_shift
+            );
+        }
+        let mut latex = format!("\\begin{{Verbatim}}[{options}]\n");
+        for tokens in lines(&self.syntax, code) {
+            for token in tokens {
+                let text = escape(token.buffer());
+                let ty = token.ty();
+                if matches!(ty, TokenType::Whitespace(_)) {
+                    latex.push_str(&text);
+                    continue;
+                }
+                let style = theme.type_style(ty);
+                let mut command = format!(
+                    "\\textcolor[HTML]{{{}}}{{{text}}}",
+                    html(theme.type_hex_color(ty))
+                );
+                for (on, wrap) in [
+                    (style.bold, "\\textbf"),
+                    (style.italic, "\\textit"),
+                    (style.underline, "\\underline"),
+                    (style.strikethrough, "\\sout"),
+                ] {
+                    if on {
+                        command = format!("{wrap}{{{command}}}");
+                    }
+                }
+                if let Some(background) = style.background {
+                    command = format!("\\colorbox[HTML]{{{}}}{{{command}}}", html(background));
+                }
+                latex.push_str(&command);
+            }
+            latex.push('\n');
+        }
+        latex.push_str("\\end{Verbatim}\n");
+        latex
+    }
+}
+
+// Color for `xcolor` HTML model, alpha is dropped.
+fn html(color: HexColor) -> String {
+    format!("{:02X}{:02X}{:02X}", color.r, color.g, color.b)
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
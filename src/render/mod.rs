@@ -0,0 +1,63 @@
+//! Highlighted code outside of egui.
+mod ansi;
+mod copy;
+mod html;
+mod latex;
+mod rtf;
+mod svg;
+#[cfg(feature = "ratatui")]
+mod tui;
+
+pub use ansi::{AnsiRenderer, ColorDepth};
+pub use copy::RichCopy;
+pub use html::HtmlRenderer;
+pub use latex::LatexRenderer;
+pub use rtf::RtfRenderer;
+pub use svg::SvgRenderer;
+#[cfg(feature = "ratatui")]
+pub use tui::CodeView;
+
+use super::highlighting::Token;
+use super::syntax::Syntax;
+
+// Tokens of every line, multiline tokens are split at line breaks.
+fn lines(syntax: &Syntax, code: &str) -> Vec<Vec<Token>> {
+    let mut lines = vec![Vec::new()];
+    for token in Token::default().tokens(syntax, code) {
+        for piece in token.buffer().split_inclusive('\n') {
+            let text = piece.strip_suffix('\n');
+            let part = text.unwrap_or(piece);
+            if let (Some(line), false) = (lines.last_mut(), part.is_empty()) {
+                line.push(Token::new(token.ty(), part));
+            }
+            if text.is_some() {
+                lines.push(Vec::new());
+            }
+        }
+    }
+    if code.ends_with('\n') {
+        lines.pop();
+    }
+    lines
+}
+
+// Line numbers right aligned to the widest one, blank below 1 if only natural ones are shown.
+fn line_labels(count: usize, shift: isize, only_natural: bool) -> Vec<String> {
+    let numbers = (1..=count as isize)
+        .map(|i| i + shift)
+        .map(|num| (num > 0 || !only_natural).then(|| num.to_string()))
+        .collect::<Vec<_>>();
+    let width = numbers.iter().flatten().map(String::len).max().unwrap_or(0);
+    numbers
+        .into_iter()
+        .map(|num| format!("{:>width$}", num.unwrap_or_default()))
+        .collect()
+}
+
+// Escapes text and attribute values of HTML and SVG.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
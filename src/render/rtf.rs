@@ -0,0 +1,159 @@
+use super::{line_labels, lines};
+use crate::syntax::{Syntax, TokenType};
+use crate::themes::{ColorTheme, HexColor};
+use std::fmt::Write;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Renders highlighted code as an RTF document, pasteable into word processors.
+///
+/// Lines are shaded with the theme background, so dark themes stay readable on a white page.
+pub struct RtfRenderer {
+    theme: ColorTheme,
+    syntax: Syntax,
+    font: String,
+    fontsize: f32,
+    numlines: bool,
+    numlines_shift: isize,
+}
+
+impl Default for RtfRenderer {
+    fn default() -> Self {
+        RtfRenderer {
+            theme: ColorTheme::GITHUB_LIGHT,
+            syntax: Syntax::rust(),
+            font: String::from("Courier New"),
+            fontsize: 10.0,
+            numlines: false,
+            numlines_shift: 0,
+        }
+    }
+}
+
+impl RtfRenderer {
+    pub fn with_theme(self, theme: ColorTheme) -> Self {
+        RtfRenderer { theme, ..self }
+    }
+
+    pub fn with_syntax(self, syntax: Syntax) -> Self {
+        RtfRenderer { syntax, ..self }
+    }
+
+    /// Monospace font family, `Courier New` by default.
+    pub fn with_font(self, font: impl Into<String>) -> Self {
+        RtfRenderer {
+            font: font.into(),
+            ..self
+        }
+    }
+
+    /// Font size in points.
+    pub fn with_fontsize(self, fontsize: f32) -> Self {
+        RtfRenderer { fontsize, ..self }
+    }
+
+    pub fn with_numlines(self, numlines: bool) -> Self {
+        RtfRenderer { numlines, ..self }
+    }
+
+    pub fn with_numlines_shift(self, numlines_shift: isize) -> Self {
+        RtfRenderer {
+            numlines_shift,
+            ..self
+        }
+    }
+
+    pub fn render(&self, code: &str) -> String {
+        let theme = &self.theme;
+        let mut colors = ColorTable::default();
+        let background = (!theme.bg.is_none()).then(|| colors.index(theme.bg));
+        let mut body = String::new();
+        let lines = lines(&self.syntax, code);
+        let labels = line_labels(lines.len(), self.numlines_shift, false);
+        for (tokens, label) in lines.iter().zip(labels) {
+            if self.numlines {
+                let color = colors.index(theme.chrome_colors().line_numbers);
+                let _ = write!(body, "{{\\cf{color} {} }}", escape(&label));
+            }
+            for token in tokens {
+                let ty = token.ty();
+                let text = escape(token.buffer());
+                if matches!(ty, TokenType::Whitespace(_)) {
+                    body.push_str(&text);
+                    continue;
+                }
+                let style = theme.type_style(ty);
+                let _ = write!(body, "{{\\cf{}", colors.index(theme.type_hex_color(ty)));
+                for (on, control) in [
+                    (style.bold, "\\b"),
+                    (style.italic, "\\i"),
+                    (style.underline, "\\ul"),
+                    (style.strikethrough, "\\strike"),
+                ] {
+                    if on {
+                        body.push_str(control);
+                    }
+                }
+                if let Some(background) = style.background {
+                    let _ = write!(body, "\\highlight{}", colors.index(background));
+                }
+                let _ = write!(body, " {text}}}");
+            }
+            body.push_str("\\line\n");
+        }
+
+        let mut rtf = format!(
+            "{{\\rtf1\\ansi\\deff0{{\\fonttbl{{\\f0\\fmodern {};}}}}\n{{\\colortbl;",
+            escape(&self.font)
+        );
+        for color in &colors.0 {
+            let _ = write!(rtf, "\\red{}\\green{}\\blue{};", color.r, color.g, color.b);
+        }
+        let size = (self.fontsize * 2.0).round() as u32;
+        let _ = write!(rtf, "}}\n\\f0\\fs{size}");
+        // Paragraph shading for Word, character shading for other editors
+        if let Some(bg) = background {
+            let _ = write!(rtf, "\\pard\\cbpat{bg}\\cb{bg}\\chcbpat{bg}");
+        }
+        let _ = write!(rtf, "\n{body}}}\n");
+        rtf
+    }
+}
+
+#[derive(Default)]
+// Colors by their index in `\colortbl`, starting with 1 after the default color.
+struct ColorTable(Vec<HexColor>);
+
+impl ColorTable {
+    fn index(&mut self, color: HexColor) -> usize {
+        let opaque = |c: &HexColor| (c.r, c.g, c.b);
+        match self.0.iter().position(|c| opaque(c) == opaque(&color)) {
+            Some(i) => i + 1,
+            None => {
+                self.0.push(color);
+                self.0.len()
+            }
+        }
+    }
+}
+
+// Escapes control characters, tabs and anything beyond ASCII as `\uN?`.
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\t' => escaped.push_str("\\tab "),
+            c if c.is_ascii() && !c.is_ascii_control() => escaped.push(c),
+            c => {
+                let mut units = [0; 2];
+                for unit in c.encode_utf16(&mut units) {
+                    let _ = write!(escaped, "\\u{}?", *unit as i16);
+                }
+            }
+        }
+    }
+    escaped
+}
@@ -0,0 +1,158 @@
+use super::{escape_xml, line_labels, lines};
+use crate::syntax::{Syntax, TokenType};
+use crate::themes::{ColorTheme, css_color};
+use std::fmt::Write;
+
+// Advance of a monospace glyph and line height, relative to font size.
+const CHAR_WIDTH: f32 = 0.6;
+const LINE_HEIGHT: f32 = 1.4;
+
+#[derive(Clone, Debug, PartialEq)]
+/// Renders highlighted code as a standalone SVG image with monospace text.
+pub struct SvgRenderer {
+    theme: ColorTheme,
+    syntax: Syntax,
+    fontsize: f32,
+    numlines: bool,
+    numlines_shift: isize,
+}
+
+impl Default for SvgRenderer {
+    fn default() -> Self {
+        SvgRenderer {
+            theme: ColorTheme::GRUVBOX,
+            syntax: Syntax::rust(),
+            fontsize: 14.0,
+            numlines: false,
+            numlines_shift: 0,
+        }
+    }
+}
+
+impl SvgRenderer {
+    pub fn with_theme(self, theme: ColorTheme) -> Self {
+        SvgRenderer { theme, ..self }
+    }
+
+    pub fn with_syntax(self, syntax: Syntax) -> Self {
+        SvgRenderer { syntax, ..self }
+    }
+
+    /// Font size in pixels, which also scales the image.
+    pub fn with_fontsize(self, fontsize: f32) -> Self {
+        SvgRenderer { fontsize, ..self }
+    }
+
+    pub fn with_numlines(self, numlines: bool) -> Self {
+        SvgRenderer { numlines, ..self }
+    }
+
+    pub fn with_numlines_shift(self, numlines_shift: isize) -> Self {
+        SvgRenderer {
+            numlines_shift,
+            ..self
+        }
+    }
+
+    pub fn render(&self, code: &str) -> String {
+        let theme = &self.theme;
+        let chrome = theme.chrome_colors();
+        let (char_width, line_height) = (self.fontsize * CHAR_WIDTH, self.fontsize * LINE_HEIGHT);
+        let padding = self.fontsize;
+        let lines = lines(&self.syntax, code);
+        let labels = line_labels(lines.len(), self.numlines_shift, false);
+        let gutter = if self.numlines {
+            labels.first().map_or(0, |label| label.chars().count() + 2)
+        } else {
+            0
+        };
+
+        let mut text = String::new();
+        let mut backgrounds = String::new();
+        let mut columns = 0;
+        for (i, (tokens, label)) in lines.iter().zip(labels).enumerate() {
+            let y = padding + line_height * i as f32;
+            let baseline = y + self.fontsize;
+            if self.numlines {
+                let _ = writeln!(
+                    text,
+                    "<text x=\"{padding}\" y=\"{baseline}\" fill=\"{}\">{}</text>",
+                    css_color(chrome.line_numbers),
+                    escape_xml(&label)
+                );
+            }
+            let _ = write!(
+                text,
+                "<text x=\"{}\" y=\"{baseline}\">",
+                padding + char_width * gutter as f32
+            );
+            let mut column = gutter;
+            for token in tokens {
+                let buffer = token.buffer().replace('\t', "    ");
+                let width = buffer.chars().count();
+                let ty = token.ty();
+                if matches!(ty, TokenType::Whitespace(_)) {
+                    text.push_str(&buffer);
+                    column += width;
+                    continue;
+                }
+                let style = theme.type_style(ty);
+                if let Some(background) = style.background {
+                    let _ = writeln!(
+                        backgrounds,
+                        "<rect x=\"{}\" y=\"{y}\" width=\"{}\" height=\"{line_height}\" fill=\"{}\"/>",
+                        padding + char_width * column as f32,
+                        char_width * width as f32,
+                        css_color(background)
+                    );
+                }
+                let mut attributes = format!(" fill=\"{}\"", css_color(theme.type_hex_color(ty)));
+                if style.bold {
+                    attributes.push_str(" font-weight=\"bold\"");
+                }
+                if style.italic {
+                    attributes.push_str(" font-style=\"italic\"");
+                }
+                let decorations = [
+                    (style.underline, "underline"),
+                    (style.strikethrough, "line-through"),
+                ]
+                .into_iter()
+                .filter_map(|(on, line)| on.then_some(line))
+                .collect::<Vec<_>>();
+                if !decorations.is_empty() {
+                    let _ = write!(attributes, " text-decoration=\"{}\"", decorations.join(" "));
+                }
+                let _ = write!(text, "<tspan{attributes}>{}</tspan>", escape_xml(&buffer));
+                column += width;
+            }
+            text.push_str("</text>\n");
+            columns = columns.max(column);
+        }
+
+        let width = 2.0 * padding + char_width * columns as f32;
+        let height = 2.0 * padding + line_height * lines.len() as f32;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\" font-family=\"monospace\" font-size=\"{}\" fill=\"{}\" xml:space=\"preserve\">\n",
+            self.fontsize,
+            css_color(theme.literals)
+        );
+        let _ = writeln!(
+            svg,
+            "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+            css_color(theme.bg)
+        );
+        if self.numlines && !chrome.gutter.is_none() {
+            let _ = writeln!(
+                svg,
+                "<rect width=\"{}\" height=\"100%\" fill=\"{}\"/>",
+                padding + char_width * gutter.saturating_sub(1) as f32,
+                css_color(chrome.gutter)
+            );
+        }
+        svg.push_str(&backgrounds);
+        svg.push_str(&text);
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
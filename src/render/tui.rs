@@ -0,0 +1,157 @@
+use super::{line_labels, lines};
+use crate::syntax::{Syntax, TokenType};
+use crate::themes::ColorTheme;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+
+#[derive(Clone, Debug, PartialEq)]
+/// ratatui widget showing highlighted code, with the same lexer and themes as `CodeEditor`.
+pub struct CodeView<'a> {
+    code: &'a str,
+    theme: ColorTheme,
+    syntax: Syntax,
+    numlines: bool,
+    numlines_shift: isize,
+    numlines_only_natural: bool,
+    scroll: usize,
+    current_line: Option<usize>,
+}
+
+impl<'a> CodeView<'a> {
+    pub fn new(code: &'a str) -> Self {
+        CodeView {
+            code,
+            theme: ColorTheme::GRUVBOX,
+            syntax: Syntax::rust(),
+            numlines: true,
+            numlines_shift: 0,
+            numlines_only_natural: false,
+            scroll: 0,
+            current_line: None,
+        }
+    }
+
+    pub fn with_theme(self, theme: ColorTheme) -> Self {
+        CodeView { theme, ..self }
+    }
+
+    pub fn with_syntax(self, syntax: Syntax) -> Self {
+        CodeView { syntax, ..self }
+    }
+
+    pub fn with_numlines(self, numlines: bool) -> Self {
+        CodeView { numlines, ..self }
+    }
+
+    pub fn with_numlines_shift(self, numlines_shift: isize) -> Self {
+        CodeView {
+            numlines_shift,
+            ..self
+        }
+    }
+
+    pub fn with_numlines_only_natural(self, numlines_only_natural: bool) -> Self {
+        CodeView {
+            numlines_only_natural,
+            ..self
+        }
+    }
+
+    /// Number of lines scrolled past the top.
+    pub fn with_scroll(self, scroll: usize) -> Self {
+        CodeView { scroll, ..self }
+    }
+
+    /// Row of the cursor counted from 0, painted with `current_line` and `active_line_number`.
+    pub fn with_current_line(self, current_line: Option<usize>) -> Self {
+        CodeView {
+            current_line,
+            ..self
+        }
+    }
+
+    /// Every highlighted line with its number, for use in other widgets such as `Paragraph`.
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let theme = &self.theme;
+        let chrome = theme.chrome_colors();
+        let lines = lines(&self.syntax, self.code);
+        let labels = line_labels(lines.len(), self.numlines_shift, self.numlines_only_natural);
+        lines
+            .iter()
+            .zip(labels)
+            .enumerate()
+            .map(|(row, (tokens, label))| {
+                let current = self.current_line == Some(row);
+                let mut spans = Vec::new();
+                if self.numlines {
+                    let color = if current {
+                        chrome.active_line_number
+                    } else {
+                        chrome.line_numbers
+                    };
+                    let style = Style::new().fg(color.into()).bg(chrome.gutter.into());
+                    spans.push(Span::styled(label + " ", style));
+                }
+                for token in tokens {
+                    let ty = token.ty();
+                    if matches!(ty, TokenType::Whitespace(_)) {
+                        spans.push(Span::raw(token.buffer().to_string()));
+                        continue;
+                    }
+                    let style = theme.type_style(ty);
+                    let mut span_style = Style::new().fg(theme.type_hex_color(ty).into());
+                    for (on, modifier) in [
+                        (style.bold, Modifier::BOLD),
+                        (style.italic, Modifier::ITALIC),
+                        (style.underline, Modifier::UNDERLINED),
+                        (style.strikethrough, Modifier::CROSSED_OUT),
+                    ] {
+                        if on {
+                            span_style = span_style.add_modifier(modifier);
+                        }
+                    }
+                    if let Some(background) = style.background {
+                        span_style = span_style.bg(background.into());
+                    }
+                    spans.push(Span::styled(token.buffer().to_string(), span_style));
+                }
+                let line = Line::from(spans);
+                if current {
+                    line.style(Style::new().bg(chrome.current_line.into()))
+                } else {
+                    line
+                }
+            })
+            .collect()
+    }
+}
+
+impl Widget for CodeView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        (&self).render(area, buf);
+    }
+}
+
+impl Widget for &CodeView<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let theme = &self.theme;
+        buf.set_style(
+            area,
+            Style::new().fg(theme.literals.into()).bg(theme.bg.into()),
+        );
+        let rows = area.top()..area.bottom();
+        for (line, y) in self.lines().into_iter().skip(self.scroll).zip(rows) {
+            line.render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+                buf,
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::Syntax;
+use super::{Rule, Syntax};
 use std::collections::BTreeSet;
 
 impl Syntax {
@@ -1158,8 +1158,8 @@ impl Syntax {
                 "setno",
                 "vpermb",
             ]),
-            types: BTreeSet::from(["ptr", "byte", "word", "dword", "qword"]),
-            special: BTreeSet::from([
+            types: BTreeSet::from([
+                "ptr", "byte", "word", "dword", "qword", // Sizes
                 "RAX", "RBX", "RCX", "RDX", "RSI", "RDI", "RBP", "RSP", "R8", "R9", "R10", "R11",
                 "R12", "R13", "R14", "R15", // 64-bit registers
                 "EAX", "EBX", "ECX", "EDX", "ESI", "EDI", "EBP", "ESP", "R8D", "R9D", "R10D",
@@ -1177,6 +1177,177 @@ impl Syntax {
                 "ZMM10", "ZMM11", "ZMM12", "ZMM13", "ZMM14", "ZMM15",
                 // ZMM
             ]),
+            special: BTreeSet::from([
+                ".align", ".ascii", ".asciz", ".balign", ".bss", ".byte", ".comm", ".data",
+                ".endm", ".equ", ".extern", ".file", ".global", ".globl", ".hword", ".include",
+                ".int", ".long", ".macro", ".p2align", ".quad", ".rodata", ".section", ".set",
+                ".short", ".size", ".space", ".string", ".text", ".type", ".word", ".zero",
+            ]),
+            rules: BTreeSet::from([Rule::Prefix('.'), Rule::Key(':')]),
         }
     }
+
+    pub fn asm_intel() -> Self {
+        Syntax::asm()
+            .with_language("Assembly (Intel)")
+            .add_types(["tbyte", "oword", "xmmword", "ymmword", "zmmword", "offset"])
+    }
+
+    pub fn asm_att() -> Self {
+        Syntax::asm()
+            .with_language("Assembly (AT&T)")
+            .with_comment("#")
+            .add_keywords([
+                "movb", "movw", "movl", "movq", "movabsq", "movsbl", "movswl", "movzbl", "movzwl",
+                "movslq", "addb", "addw", "addl", "addq", "subb", "subw", "subl", "subq", "andb",
+                "andw", "andl", "andq", "orb", "orw", "orl", "orq", "xorb", "xorw", "xorl", "xorq",
+                "cmpb", "cmpw", "cmpl", "cmpq", "testb", "testw", "testl", "testq", "incb", "incw",
+                "incl", "incq", "decb", "decw", "decl", "decq", "negl", "negq", "notl", "notq",
+                "shll", "shlq", "shrl", "shrq", "sarl", "sarq", "leal", "leaq", "pushl", "pushq",
+                "popl", "popq", "imull", "imulq", "idivl", "idivq", "cltq", "cqto", "callq",
+                "retq", "jmpq",
+            ])
+            .add_rules([Rule::Registers('%'), Rule::Prefix('$')])
+    }
+
+    pub fn asm_arm64() -> Self {
+        Syntax::asm()
+            .with_language("Assembly (ARM64)")
+            .with_comment("//")
+            .with_keywords([
+                "adc", "adcs", "add", "adds", "adr", "adrp", "and", "ands", "asr", "b", "bfi",
+                "bfm", "bic", "bics", "bl", "blr", "br", "brk", "cbnz", "cbz", "ccmn", "ccmp",
+                "cinc", "cinv", "clrex", "cls", "clz", "cmn", "cmp", "cneg", "csel", "cset",
+                "csetm", "csinc", "csinv", "csneg", "dmb", "dsb", "eon", "eor", "eret", "extr",
+                "fabs", "fadd", "fcmp", "fcsel", "fcvt", "fcvtzs", "fcvtzu", "fdiv", "fmadd",
+                "fmax", "fmin", "fmov", "fmul", "fneg", "fsqrt", "fsub", "hlt", "hvc", "isb",
+                "ld1", "ld2", "ldar", "ldaxr", "ldnp", "ldp", "ldpsw", "ldr", "ldrb", "ldrh",
+                "ldrsb", "ldrsh", "ldrsw", "ldur", "ldxr", "lsl", "lsr", "madd", "mneg", "mov",
+                "movk", "movn", "movz", "mrs", "msr", "msub", "mul", "mvn", "neg", "negs", "ngc",
+                "nop", "orn", "orr", "prfm", "rbit", "ret", "rev", "rev16", "rev32", "ror", "sbc",
+                "sbcs", "sbfiz", "sbfx", "scvtf", "sdiv", "sev", "smaddl", "smc", "smulh", "smull",
+                "st1", "st2", "stlr", "stlxr", "stnp", "stp", "str", "strb", "strh", "stur",
+                "stxr", "sub", "subs", "svc", "sxtb", "sxth", "sxtw", "sys", "tbnz", "tbz", "tst",
+                "ubfiz", "ubfx", "ucvtf", "udiv", "umaddl", "umulh", "umull", "uxtb", "uxth",
+                "wfe", "wfi", "yield",
+            ])
+            .with_types([
+                "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12",
+                "x13", "x14", "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24",
+                "x25", "x26", "x27", "x28", "x29", "x30", "w0", "w1", "w2", "w3", "w4", "w5", "w6",
+                "w7", "w8", "w9", "w10", "w11", "w12", "w13", "w14", "w15", "w16", "w17", "w18",
+                "w19", "w20", "w21", "w22", "w23", "w24", "w25", "w26", "w27", "w28", "w29", "w30",
+                "sp", "wsp", "xzr", "wzr", "lr", "fp", "pc", "nzcv", "fpcr", "fpsr", "v0", "v1",
+                "v2", "v3", "v4", "v5", "v6", "v7", "v8", "v9", "v10", "v11", "v12", "v13", "v14",
+                "v15", "v16", "v17", "v18", "v19", "v20", "v21", "v22", "v23", "v24", "v25", "v26",
+                "v27", "v28", "v29", "v30", "v31", "q0", "q1", "q2", "q3", "q4", "q5", "q6", "q7",
+                "q8", "q9", "q10", "q11", "q12", "q13", "q14", "q15", "q16", "q17", "q18", "q19",
+                "q20", "q21", "q22", "q23", "q24", "q25", "q26", "q27", "q28", "q29", "q30", "q31",
+                "d0", "d1", "d2", "d3", "d4", "d5", "d6", "d7", "d8", "d9", "d10", "d11", "d12",
+                "d13", "d14", "d15", "d16", "d17", "d18", "d19", "d20", "d21", "d22", "d23", "d24",
+                "d25", "d26", "d27", "d28", "d29", "d30", "d31", "s0", "s1", "s2", "s3", "s4",
+                "s5", "s6", "s7", "s8", "s9", "s10", "s11", "s12", "s13", "s14", "s15", "s16",
+                "s17", "s18", "s19", "s20", "s21", "s22", "s23", "s24", "s25", "s26", "s27", "s28",
+                "s29", "s30", "s31", "h0", "h1", "h2", "h3", "h4", "h5", "h6", "h7", "h8", "h9",
+                "h10", "h11", "h12", "h13", "h14", "h15", "h16", "h17", "h18", "h19", "h20", "h21",
+                "h22", "h23", "h24", "h25", "h26", "h27", "h28", "h29", "h30", "h31", "b0", "b1",
+                "b2", "b3", "b4", "b5", "b6", "b7", "b8", "b9", "b10", "b11", "b12", "b13", "b14",
+                "b15", "b16", "b17", "b18", "b19", "b20", "b21", "b22", "b23", "b24", "b25", "b26",
+                "b27", "b28", "b29", "b30", "b31",
+            ])
+            .add_special([
+                ".arch",
+                ".cfi_endproc",
+                ".cfi_startproc",
+                ".inst",
+                ".ltorg",
+                ".pool",
+                ".req",
+                ".unreq",
+            ])
+    }
+
+    pub fn asm_thumb() -> Self {
+        Syntax::asm()
+            .with_language("Assembly (ARMv7 Thumb)")
+            .with_comment("@")
+            .with_keywords([
+                "adc", "adcs", "add", "adds", "adr", "and", "ands", "asr", "asrs", "b", "bfc",
+                "bfi", "bic", "bics", "bkpt", "bl", "blx", "bx", "cbnz", "cbz", "clz", "cmn",
+                "cmp", "cpsid", "cpsie", "dmb", "dsb", "eor", "eors", "isb", "it", "ite", "itee",
+                "itet", "itt", "itte", "ittt", "ldm", "ldmdb", "ldmia", "ldr", "ldrb", "ldrd",
+                "ldrex", "ldrh", "ldrsb", "ldrsh", "lsl", "lsls", "lsr", "lsrs", "mla", "mls",
+                "mov", "movs", "movt", "movw", "mrs", "msr", "mul", "muls", "mvn", "mvns", "neg",
+                "nop", "orn", "orr", "orrs", "pop", "push", "rev", "rev16", "ror", "rors", "rsb",
+                "rsbs", "sbc", "sbcs", "sbfx", "sdiv", "sev", "smull", "stm", "stmdb", "stmia",
+                "str", "strb", "strd", "strex", "strh", "sub", "subs", "svc", "sxtb", "sxth",
+                "tbb", "tbh", "teq", "tst", "ubfx", "udiv", "umull", "uxtb", "uxth", "vadd",
+                "vdiv", "vldr", "vmov", "vmul", "vpop", "vpush", "vstr", "vsub", "wfe", "wfi",
+                "yield",
+            ])
+            .with_types([
+                "r0", "r1", "r2", "r3", "r4", "r5", "r6", "r7", "r8", "r9", "r10", "r11", "r12",
+                "r13", "r14", "r15", "sp", "lr", "pc", "ip", "fp", "sb", "sl", "apsr", "cpsr",
+                "spsr", "fpscr", "primask", "basepri", "control", "s0", "s1", "s2", "s3", "s4",
+                "s5", "s6", "s7", "s8", "s9", "s10", "s11", "s12", "s13", "s14", "s15", "s16",
+                "s17", "s18", "s19", "s20", "s21", "s22", "s23", "s24", "s25", "s26", "s27", "s28",
+                "s29", "s30", "s31", "d0", "d1", "d2", "d3", "d4", "d5", "d6", "d7", "d8", "d9",
+                "d10", "d11", "d12", "d13", "d14", "d15", "d16", "d17", "d18", "d19", "d20", "d21",
+                "d22", "d23", "d24", "d25", "d26", "d27", "d28", "d29", "d30", "d31", "q0", "q1",
+                "q2", "q3", "q4", "q5", "q6", "q7", "q8", "q9", "q10", "q11", "q12", "q13", "q14",
+                "q15",
+            ])
+            .add_special([
+                ".arm",
+                ".code",
+                ".cpu",
+                ".fpu",
+                ".ltorg",
+                ".pool",
+                ".syntax",
+                ".thumb",
+                ".thumb_func",
+                "unified",
+            ])
+    }
+
+    pub fn asm_riscv() -> Self {
+        Syntax::asm()
+            .with_language("Assembly (RISC-V)")
+            .with_comment("#")
+            .with_keywords([
+                "add", "addi", "addiw", "addw", "and", "andi", "auipc", "beq", "beqz", "bge",
+                "bgeu", "bgez", "blt", "bltu", "bltz", "bne", "bnez", "call", "csrr", "csrrc",
+                "csrrci", "csrrs", "csrrsi", "csrrw", "csrrwi", "csrw", "div", "divu", "divuw",
+                "divw", "ebreak", "ecall", "fadd", "fcvt", "fdiv", "fence", "fld", "flw", "fmul",
+                "fmv", "fsd", "fsub", "fsw", "j", "jal", "jalr", "jr", "la", "lb", "lbu", "ld",
+                "lh", "lhu", "li", "lui", "lw", "lwu", "mret", "mul", "mulh", "mulhsu", "mulhu",
+                "mulw", "mv", "neg", "nop", "not", "or", "ori", "rem", "remu", "remuw", "remw",
+                "ret", "sb", "sd", "seqz", "sh", "sll", "slli", "slliw", "sllw", "slt", "slti",
+                "sltiu", "sltu", "snez", "sra", "srai", "sraiw", "sraw", "sret", "srl", "srli",
+                "srliw", "srlw", "sub", "subw", "sw", "tail", "wfi", "xor", "xori",
+            ])
+            .with_types([
+                "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12",
+                "x13", "x14", "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24",
+                "x25", "x26", "x27", "x28", "x29", "x30", "x31", "zero", "ra", "sp", "gp", "tp",
+                "fp", "pc", "t0", "t1", "t2", "t3", "t4", "t5", "t6", "s0", "s1", "s2", "s3", "s4",
+                "s5", "s6", "s7", "s8", "s9", "s10", "s11", "a0", "a1", "a2", "a3", "a4", "a5",
+                "a6", "a7", "f0", "f1", "f2", "f3", "f4", "f5", "f6", "f7", "f8", "f9", "f10",
+                "f11", "f12", "f13", "f14", "f15", "f16", "f17", "f18", "f19", "f20", "f21", "f22",
+                "f23", "f24", "f25", "f26", "f27", "f28", "f29", "f30", "f31", "ft0", "ft1", "ft2",
+                "ft3", "ft4", "ft5", "ft6", "ft7", "ft8", "ft9", "ft10", "ft11", "fs0", "fs1",
+                "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9", "fs10", "fs11", "fa0",
+                "fa1", "fa2", "fa3", "fa4", "fa5", "fa6", "fa7",
+            ])
+            .add_special([
+                ".attribute",
+                ".option",
+                "%hi",
+                "%lo",
+                "%pcrel_hi",
+                "%pcrel_lo",
+            ])
+            .add_rules([Rule::Prefix('%')])
+    }
 }
@@ -0,0 +1,250 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn dockerfile() -> Self {
+        Syntax {
+            language: "Dockerfile",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["#"; 2],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "ADD",
+                "ARG",
+                "AS",
+                "CMD",
+                "COPY",
+                "ENTRYPOINT",
+                "ENV",
+                "EXPOSE",
+                "FROM",
+                "HEALTHCHECK",
+                "LABEL",
+                "MAINTAINER",
+                "ONBUILD",
+                "RUN",
+                "SHELL",
+                "STOPSIGNAL",
+                "USER",
+                "VOLUME",
+                "WORKDIR",
+            ]),
+            types: BTreeSet::new(),
+            special: BTreeSet::from(["scratch"]),
+            rules: BTreeSet::from([Rule::Key('='), Rule::Variables('$')]),
+        }
+    }
+
+    pub fn makefile() -> Self {
+        Syntax {
+            language: "Makefile",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["#"; 2],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "define", "else", "endef", "endif", "export", "ifdef", "ifeq", "ifndef", "ifneq",
+                "include", "override", "private", "undefine", "unexport", "vpath",
+            ]),
+            types: BTreeSet::from([
+                "AR",
+                "CC",
+                "CFLAGS",
+                "CPPFLAGS",
+                "CURDIR",
+                "CXX",
+                "CXXFLAGS",
+                "LDFLAGS",
+                "LDLIBS",
+                "MAKE",
+                "MAKEFLAGS",
+                "RM",
+                "SHELL",
+            ]),
+            special: BTreeSet::from([
+                // Special targets
+                ".DEFAULT",
+                ".DELETE_ON_ERROR",
+                ".INTERMEDIATE",
+                ".NOTPARALLEL",
+                ".ONESHELL",
+                ".PHONY",
+                ".PRECIOUS",
+                ".SECONDARY",
+                ".SILENT",
+                ".SUFFIXES",
+                // Functions
+                "abspath",
+                "addprefix",
+                "addsuffix",
+                "basename",
+                "call",
+                "dir",
+                "error",
+                "eval",
+                "filter",
+                "findstring",
+                "firstword",
+                "foreach",
+                "info",
+                "join",
+                "notdir",
+                "origin",
+                "patsubst",
+                "realpath",
+                "shell",
+                "sort",
+                "strip",
+                "subst",
+                "suffix",
+                "value",
+                "warning",
+                "wildcard",
+                "word",
+                "words",
+            ]),
+            rules: BTreeSet::from([
+                Rule::Key(':'),
+                Rule::Key('='),
+                Rule::Variables('$'),
+                Rule::Recipes,
+            ]),
+        }
+    }
+
+    pub fn cmake() -> Self {
+        Syntax {
+            language: "CMake",
+            case_sensitive: false,
+            comment: "#",
+            comment_multiline: ["#"; 2],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "block",
+                "break",
+                "continue",
+                "else",
+                "elseif",
+                "endblock",
+                "endforeach",
+                "endfunction",
+                "endif",
+                "endmacro",
+                "endwhile",
+                "foreach",
+                "function",
+                "if",
+                "macro",
+                "return",
+                "while",
+            ]),
+            types: BTreeSet::from([
+                "CMAKE_BINARY_DIR",
+                "CMAKE_BUILD_TYPE",
+                "CMAKE_CURRENT_BINARY_DIR",
+                "CMAKE_CURRENT_SOURCE_DIR",
+                "CMAKE_CXX_STANDARD",
+                "CMAKE_C_STANDARD",
+                "CMAKE_INSTALL_PREFIX",
+                "CMAKE_MODULE_PATH",
+                "CMAKE_SOURCE_DIR",
+                "PROJECT_NAME",
+                "PROJECT_SOURCE_DIR",
+            ]),
+            special: BTreeSet::from([
+                "AND",
+                "BOOL",
+                "CACHE",
+                "COMPONENTS",
+                "DEFINED",
+                "EQUAL",
+                "EXISTS",
+                "FALSE",
+                "FATAL_ERROR",
+                "FORCE",
+                "INTERFACE",
+                "MATCHES",
+                "NOT",
+                "OFF",
+                "ON",
+                "OR",
+                "PARENT_SCOPE",
+                "PATH",
+                "PRIVATE",
+                "PUBLIC",
+                "REQUIRED",
+                "STATUS",
+                "STREQUAL",
+                "STRING",
+                "TARGET",
+                "TRUE",
+                "VERSION",
+                "WARNING",
+            ]),
+            rules: BTreeSet::from([Rule::Variables('$')]),
+        }
+    }
+
+    pub fn nix() -> Self {
+        Syntax {
+            language: "Nix",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "assert", "else", "if", "in", "inherit", "let", "or", "rec", "then", "with",
+            ]),
+            types: BTreeSet::new(),
+            special: BTreeSet::from([
+                "abort",
+                "builtins",
+                "derivation",
+                "false",
+                "fetchTarball",
+                "fetchurl",
+                "import",
+                "map",
+                "null",
+                "throw",
+                "toString",
+                "true",
+            ]),
+            rules: BTreeSet::from([Rule::Key('='), Rule::Variables('$'), Rule::Apostrophes]),
+        }
+    }
+
+    pub fn hcl() -> Self {
+        Syntax {
+            language: "HCL",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "content",
+                "data",
+                "dynamic",
+                "for",
+                "if",
+                "in",
+                "locals",
+                "module",
+                "moved",
+                "output",
+                "provider",
+                "resource",
+                "terraform",
+                "variable",
+            ]),
+            types: BTreeSet::from([
+                "any", "bool", "list", "map", "number", "object", "set", "string", "tuple",
+            ]),
+            special: BTreeSet::from([
+                "count", "each", "false", "local", "null", "path", "self", "true", "var",
+            ]),
+            rules: BTreeSet::from([Rule::Key('=')]),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn c() -> Self {
+        Syntax {
+            language: "C",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "auto",
+                "break",
+                "case",
+                "const",
+                "continue",
+                "default",
+                "do",
+                "else",
+                "enum",
+                "extern",
+                "for",
+                "goto",
+                "if",
+                "inline",
+                "register",
+                "restrict",
+                "return",
+                "sizeof",
+                "static",
+                "struct",
+                "switch",
+                "typedef",
+                "union",
+                "volatile",
+                "while",
+                "_Alignas",
+                "_Alignof",
+                "_Atomic",
+                "_Generic",
+                "_Noreturn",
+                "_Static_assert",
+                "_Thread_local",
+            ]),
+            types: BTreeSet::from([
+                // Primitives
+                "char",
+                "double",
+                "float",
+                "int",
+                "long",
+                "short",
+                "signed",
+                "unsigned",
+                "void",
+                "_Bool",
+                "_Complex",
+                "_Imaginary",
+                // stddef.h, stdint.h, stdio.h
+                "bool",
+                "size_t",
+                "ssize_t",
+                "ptrdiff_t",
+                "intptr_t",
+                "uintptr_t",
+                "int8_t",
+                "int16_t",
+                "int32_t",
+                "int64_t",
+                "uint8_t",
+                "uint16_t",
+                "uint32_t",
+                "uint64_t",
+                "wchar_t",
+                "FILE",
+                "va_list",
+            ]),
+            special: BTreeSet::from([
+                "NULL", "EOF", "true", "false", "stdin", "stdout", "stderr", "errno",
+                // Preprocessor
+                "#define", "#elif", "#else", "#endif", "#error", "#if", "#ifdef", "#ifndef",
+                "#include", "#line", "#pragma", "#undef", "#warning", "defined",
+            ]),
+            rules: BTreeSet::from([Rule::Prefix('#')]),
+        }
+    }
+
+    pub fn cpp() -> Self {
+        Syntax::c()
+            .with_language("C++")
+            .add_keywords([
+                "alignas",
+                "alignof",
+                "and",
+                "and_eq",
+                "asm",
+                "bitand",
+                "bitor",
+                "catch",
+                "class",
+                "co_await",
+                "co_return",
+                "co_yield",
+                "compl",
+                "concept",
+                "const_cast",
+                "consteval",
+                "constexpr",
+                "constinit",
+                "decltype",
+                "delete",
+                "dynamic_cast",
+                "explicit",
+                "export",
+                "final",
+                "friend",
+                "mutable",
+                "namespace",
+                "new",
+                "noexcept",
+                "not",
+                "not_eq",
+                "operator",
+                "or",
+                "or_eq",
+                "override",
+                "private",
+                "protected",
+                "public",
+                "reinterpret_cast",
+                "requires",
+                "static_assert",
+                "static_cast",
+                "template",
+                "thread_local",
+                "throw",
+                "try",
+                "typeid",
+                "typename",
+                "using",
+                "virtual",
+                "xor",
+                "xor_eq",
+            ])
+            .add_types([
+                "char8_t",
+                "char16_t",
+                "char32_t",
+                "std",
+                "string",
+                "string_view",
+                "array",
+                "vector",
+                "deque",
+                "list",
+                "map",
+                "set",
+                "unordered_map",
+                "unordered_set",
+                "pair",
+                "tuple",
+                "optional",
+                "variant",
+                "function",
+                "unique_ptr",
+                "shared_ptr",
+                "weak_ptr",
+            ])
+            .add_special(["nullptr", "this"])
+    }
+}
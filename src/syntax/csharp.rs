@@ -0,0 +1,147 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn csharp() -> Self {
+        Syntax {
+            language: "C#",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "abstract",
+                "as",
+                "async",
+                "await",
+                "base",
+                "break",
+                "case",
+                "catch",
+                "checked",
+                "class",
+                "const",
+                "continue",
+                "default",
+                "delegate",
+                "do",
+                "else",
+                "enum",
+                "event",
+                "explicit",
+                "extern",
+                "finally",
+                "fixed",
+                "for",
+                "foreach",
+                "get",
+                "goto",
+                "if",
+                "implicit",
+                "in",
+                "init",
+                "interface",
+                "internal",
+                "is",
+                "lock",
+                "nameof",
+                "namespace",
+                "new",
+                "operator",
+                "out",
+                "override",
+                "params",
+                "partial",
+                "private",
+                "protected",
+                "public",
+                "readonly",
+                "record",
+                "ref",
+                "return",
+                "sealed",
+                "set",
+                "sizeof",
+                "stackalloc",
+                "static",
+                "struct",
+                "switch",
+                "throw",
+                "try",
+                "typeof",
+                "unchecked",
+                "unsafe",
+                "using",
+                "var",
+                "virtual",
+                "volatile",
+                "when",
+                "where",
+                "while",
+                "yield",
+            ]),
+            types: BTreeSet::from([
+                // Built-in
+                "bool",
+                "byte",
+                "sbyte",
+                "char",
+                "decimal",
+                "double",
+                "float",
+                "int",
+                "uint",
+                "long",
+                "ulong",
+                "short",
+                "ushort",
+                "nint",
+                "nuint",
+                "object",
+                "string",
+                "void",
+                "dynamic",
+                // System
+                "Action",
+                "Boolean",
+                "DateTime",
+                "Dictionary",
+                "Exception",
+                "Func",
+                "HashSet",
+                "IEnumerable",
+                "IList",
+                "Int32",
+                "Int64",
+                "List",
+                "Object",
+                "Span",
+                "String",
+                "Task",
+                "TimeSpan",
+            ]),
+            special: BTreeSet::from([
+                "null",
+                "true",
+                "false",
+                "this",
+                "value",
+                // Preprocessor
+                "#define",
+                "#elif",
+                "#else",
+                "#endif",
+                "#endregion",
+                "#error",
+                "#if",
+                "#line",
+                "#nullable",
+                "#pragma",
+                "#region",
+                "#undef",
+                "#warning",
+            ]),
+            rules: BTreeSet::from([Rule::Prefix('#')]),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn json() -> Self {
+        Syntax {
+            language: "JSON",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::new(),
+            types: BTreeSet::new(),
+            special: BTreeSet::from(["true", "false", "null"]),
+            rules: BTreeSet::from([Rule::Key(':')]),
+        }
+    }
+
+    pub fn toml() -> Self {
+        Syntax {
+            language: "TOML",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["#"; 2],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::new(),
+            types: BTreeSet::new(),
+            special: BTreeSet::from(["true", "false", "inf", "nan"]),
+            rules: BTreeSet::from([Rule::Key('='), Rule::Sections, Rule::TripleQuotes]),
+        }
+    }
+
+    pub fn yaml() -> Self {
+        Syntax {
+            language: "YAML",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["#"; 2],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::new(),
+            types: BTreeSet::new(),
+            special: BTreeSet::from([
+                "true", "false", "True", "False", "TRUE", "FALSE", "yes", "no", "on", "off",
+                "null", "Null", "NULL",
+            ]),
+            rules: BTreeSet::from([
+                Rule::Key(':'),
+                Rule::Apostrophes,
+                Rule::Prefix('&'),
+                Rule::Prefix('*'),
+            ]),
+        }
+    }
+
+    pub fn ini() -> Self {
+        Syntax {
+            language: "INI",
+            case_sensitive: false,
+            comment: ";",
+            comment_multiline: [";"; 2],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::new(),
+            types: BTreeSet::new(),
+            special: BTreeSet::from(["TRUE", "FALSE", "YES", "NO", "ON", "OFF"]),
+            rules: BTreeSet::from([Rule::Key('='), Rule::Sections, Rule::Apostrophes]),
+        }
+    }
+
+    pub fn csv() -> Self {
+        Syntax {
+            language: "CSV",
+            case_sensitive: true,
+            comment: "",
+            comment_multiline: [""; 2],
+            hyperlinks: BTreeSet::new(),
+            keywords: BTreeSet::new(),
+            types: BTreeSet::new(),
+            special: BTreeSet::new(),
+            rules: BTreeSet::from([Rule::Columns(',')]),
+        }
+    }
+
+    pub fn tsv() -> Self {
+        Syntax::csv()
+            .with_language("TSV")
+            .with_rules([Rule::Columns('\t')])
+    }
+}
@@ -0,0 +1,278 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn haskell() -> Self {
+        Syntax {
+            language: "Haskell",
+            case_sensitive: true,
+            comment: "--",
+            comment_multiline: ["{-", "-}"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "as",
+                "case",
+                "class",
+                "data",
+                "default",
+                "deriving",
+                "do",
+                "else",
+                "family",
+                "forall",
+                "foreign",
+                "hiding",
+                "if",
+                "import",
+                "in",
+                "infix",
+                "infixl",
+                "infixr",
+                "instance",
+                "let",
+                "mdo",
+                "module",
+                "newtype",
+                "of",
+                "qualified",
+                "then",
+                "type",
+                "where",
+            ]),
+            types: BTreeSet::from([
+                "Applicative",
+                "Bool",
+                "Bounded",
+                "Char",
+                "Double",
+                "Either",
+                "Enum",
+                "Eq",
+                "Float",
+                "Foldable",
+                "Functor",
+                "IO",
+                "Int",
+                "Integer",
+                "Maybe",
+                "Monad",
+                "Monoid",
+                "Num",
+                "Ord",
+                "Ordering",
+                "Read",
+                "Semigroup",
+                "Show",
+                "String",
+                "Traversable",
+                "Word",
+            ]),
+            special: BTreeSet::from([
+                "EQ",
+                "False",
+                "GT",
+                "Just",
+                "LT",
+                "Left",
+                "Nothing",
+                "Right",
+                "True",
+                "error",
+                "fmap",
+                "map",
+                "otherwise",
+                "print",
+                "pure",
+                "putStrLn",
+                "return",
+                "show",
+                "undefined",
+            ]),
+            rules: BTreeSet::from([Rule::Apostrophes, Rule::Primes]),
+        }
+    }
+
+    pub fn ocaml() -> Self {
+        Syntax {
+            language: "OCaml",
+            case_sensitive: true,
+            comment: "",
+            comment_multiline: ["(*", "*)"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "and",
+                "as",
+                "assert",
+                "begin",
+                "class",
+                "constraint",
+                "do",
+                "done",
+                "downto",
+                "else",
+                "end",
+                "exception",
+                "external",
+                "for",
+                "fun",
+                "function",
+                "functor",
+                "if",
+                "in",
+                "include",
+                "inherit",
+                "initializer",
+                "lazy",
+                "let",
+                "match",
+                "method",
+                "module",
+                "mutable",
+                "new",
+                "nonrec",
+                "object",
+                "of",
+                "open",
+                "or",
+                "private",
+                "rec",
+                "sig",
+                "struct",
+                "then",
+                "to",
+                "try",
+                "type",
+                "val",
+                "virtual",
+                "when",
+                "while",
+                "with",
+            ]),
+            types: BTreeSet::from([
+                "array",
+                "bool",
+                "bytes",
+                "char",
+                "exn",
+                "float",
+                "format",
+                "int",
+                "int32",
+                "int64",
+                "list",
+                "nativeint",
+                "option",
+                "ref",
+                "result",
+                "string",
+                "unit",
+            ]),
+            special: BTreeSet::from([
+                "Error",
+                "None",
+                "Ok",
+                "Some",
+                "failwith",
+                "false",
+                "ignore",
+                "not",
+                "print_endline",
+                "print_string",
+                "raise",
+                "true",
+            ]),
+            rules: BTreeSet::from([Rule::Apostrophes, Rule::CharLiterals]),
+        }
+    }
+
+    pub fn elixir() -> Self {
+        Syntax {
+            language: "Elixir",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["#"; 2],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "after",
+                "alias",
+                "and",
+                "case",
+                "catch",
+                "cond",
+                "def",
+                "defdelegate",
+                "defexception",
+                "defguard",
+                "defimpl",
+                "defmacro",
+                "defmacrop",
+                "defmodule",
+                "defp",
+                "defprotocol",
+                "defstruct",
+                "do",
+                "else",
+                "end",
+                "fn",
+                "for",
+                "if",
+                "import",
+                "in",
+                "not",
+                "or",
+                "quote",
+                "receive",
+                "require",
+                "rescue",
+                "try",
+                "unless",
+                "unquote",
+                "use",
+                "when",
+                "with",
+            ]),
+            types: BTreeSet::from([
+                "Agent",
+                "Atom",
+                "Enum",
+                "File",
+                "Float",
+                "GenServer",
+                "IO",
+                "Integer",
+                "Kernel",
+                "Keyword",
+                "List",
+                "Map",
+                "Process",
+                "Regex",
+                "Stream",
+                "String",
+                "Supervisor",
+                "Task",
+                "Tuple",
+            ]),
+            special: BTreeSet::from([
+                "__MODULE__",
+                "false",
+                "nil",
+                "raise",
+                "true",
+                // Module attributes
+                "@behaviour",
+                "@callback",
+                "@doc",
+                "@impl",
+                "@moduledoc",
+                "@spec",
+                "@type",
+            ]),
+            rules: BTreeSet::from([
+                Rule::Prefix(':'),
+                Rule::Prefix('@'),
+                Rule::Sigils('~'),
+                Rule::TripleQuotes,
+            ]),
+        }
+    }
+}
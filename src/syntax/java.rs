@@ -0,0 +1,95 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn java() -> Self {
+        Syntax {
+            language: "Java",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "abstract",
+                "assert",
+                "break",
+                "case",
+                "catch",
+                "class",
+                "const",
+                "continue",
+                "default",
+                "do",
+                "else",
+                "enum",
+                "extends",
+                "final",
+                "finally",
+                "for",
+                "goto",
+                "if",
+                "implements",
+                "import",
+                "instanceof",
+                "interface",
+                "native",
+                "new",
+                "package",
+                "permits",
+                "private",
+                "protected",
+                "public",
+                "record",
+                "return",
+                "sealed",
+                "static",
+                "strictfp",
+                "switch",
+                "synchronized",
+                "throw",
+                "throws",
+                "transient",
+                "try",
+                "var",
+                "volatile",
+                "while",
+                "yield",
+            ]),
+            types: BTreeSet::from([
+                // Primitives
+                "boolean",
+                "byte",
+                "char",
+                "double",
+                "float",
+                "int",
+                "long",
+                "short",
+                "void",
+                // java.lang, java.util
+                "Boolean",
+                "Character",
+                "Double",
+                "Exception",
+                "Float",
+                "Integer",
+                "Long",
+                "Object",
+                "RuntimeException",
+                "String",
+                "StringBuilder",
+                "Thread",
+                "ArrayList",
+                "HashMap",
+                "HashSet",
+                "List",
+                "Map",
+                "Optional",
+                "Set",
+                "Stream",
+            ]),
+            special: BTreeSet::from(["true", "false", "null", "this", "super"]),
+            rules: BTreeSet::from([Rule::Prefix('@'), Rule::TripleQuotes]),
+        }
+    }
+}
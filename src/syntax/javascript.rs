@@ -0,0 +1,152 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn javascript() -> Self {
+        Syntax {
+            language: "JavaScript",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "async",
+                "await",
+                "break",
+                "case",
+                "catch",
+                "class",
+                "const",
+                "continue",
+                "debugger",
+                "default",
+                "delete",
+                "do",
+                "else",
+                "export",
+                "extends",
+                "finally",
+                "for",
+                "from",
+                "function",
+                "get",
+                "if",
+                "import",
+                "in",
+                "instanceof",
+                "let",
+                "new",
+                "of",
+                "return",
+                "set",
+                "static",
+                "switch",
+                "throw",
+                "try",
+                "typeof",
+                "var",
+                "void",
+                "while",
+                "with",
+                "yield",
+            ]),
+            types: BTreeSet::from([
+                "Array",
+                "ArrayBuffer",
+                "BigInt",
+                "Boolean",
+                "DataView",
+                "Date",
+                "Error",
+                "Float32Array",
+                "Float64Array",
+                "Function",
+                "Int8Array",
+                "Int16Array",
+                "Int32Array",
+                "Map",
+                "Number",
+                "Object",
+                "Promise",
+                "Proxy",
+                "RangeError",
+                "RegExp",
+                "Set",
+                "String",
+                "Symbol",
+                "TypeError",
+                "Uint8Array",
+                "Uint16Array",
+                "Uint32Array",
+                "WeakMap",
+                "WeakRef",
+                "WeakSet",
+            ]),
+            special: BTreeSet::from([
+                "Infinity",
+                "NaN",
+                "arguments",
+                "console",
+                "document",
+                "false",
+                "globalThis",
+                "null",
+                "super",
+                "this",
+                "true",
+                "undefined",
+                "window",
+            ]),
+            rules: BTreeSet::from([Rule::Regex, Rule::Jsx]),
+        }
+    }
+
+    pub fn typescript() -> Self {
+        Syntax::javascript()
+            .with_language("TypeScript")
+            .add_keywords([
+                "abstract",
+                "as",
+                "asserts",
+                "declare",
+                "enum",
+                "implements",
+                "infer",
+                "interface",
+                "is",
+                "keyof",
+                "module",
+                "namespace",
+                "override",
+                "private",
+                "protected",
+                "public",
+                "readonly",
+                "satisfies",
+                "type",
+                "unique",
+            ])
+            .add_types([
+                "any",
+                "bigint",
+                "boolean",
+                "never",
+                "number",
+                "object",
+                "string",
+                "symbol",
+                "unknown",
+                "Awaited",
+                "Exclude",
+                "Extract",
+                "NonNullable",
+                "Omit",
+                "Partial",
+                "Pick",
+                "Readonly",
+                "Record",
+                "Required",
+                "ReturnType",
+            ])
+    }
+}
@@ -0,0 +1,107 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn kotlin() -> Self {
+        Syntax {
+            language: "Kotlin",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                // Hard
+                "as",
+                "break",
+                "class",
+                "continue",
+                "do",
+                "else",
+                "for",
+                "fun",
+                "if",
+                "in",
+                "interface",
+                "is",
+                "object",
+                "package",
+                "return",
+                "throw",
+                "try",
+                "typealias",
+                "typeof",
+                "val",
+                "var",
+                "when",
+                "while",
+                // Soft
+                "by",
+                "catch",
+                "constructor",
+                "finally",
+                "get",
+                "import",
+                "init",
+                "set",
+                "where",
+                // Modifiers
+                "abstract",
+                "actual",
+                "annotation",
+                "companion",
+                "const",
+                "crossinline",
+                "data",
+                "enum",
+                "expect",
+                "external",
+                "final",
+                "infix",
+                "inline",
+                "inner",
+                "internal",
+                "lateinit",
+                "noinline",
+                "open",
+                "operator",
+                "out",
+                "override",
+                "private",
+                "protected",
+                "public",
+                "reified",
+                "sealed",
+                "suspend",
+                "tailrec",
+                "vararg",
+            ]),
+            types: BTreeSet::from([
+                "Any",
+                "Array",
+                "Boolean",
+                "Byte",
+                "Char",
+                "Double",
+                "Float",
+                "Int",
+                "IntArray",
+                "List",
+                "Long",
+                "Map",
+                "MutableList",
+                "MutableMap",
+                "MutableSet",
+                "Nothing",
+                "Pair",
+                "Sequence",
+                "Set",
+                "Short",
+                "String",
+                "Triple",
+                "Unit",
+            ]),
+            special: BTreeSet::from(["true", "false", "null", "this", "super", "it"]),
+            rules: BTreeSet::from([Rule::Prefix('@'), Rule::TripleQuotes]),
+        }
+    }
+}
@@ -17,6 +17,7 @@ impl Syntax {
                 "boolean", "number", "string", "function", "userdata", "thread", "table",
             ]),
             special: BTreeSet::from(["false", "nil", "true"]),
+            rules: BTreeSet::new(),
         }
     }
 }
@@ -0,0 +1,86 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn markdown() -> Self {
+        Syntax {
+            language: "Markdown",
+            case_sensitive: true,
+            comment: "",
+            comment_multiline: ["<!--", "-->"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::new(),
+            types: BTreeSet::new(),
+            special: BTreeSet::new(),
+            rules: BTreeSet::from([
+                Rule::Headings('#'),
+                Rule::Emphasis,
+                Rule::Prose,
+                Rule::TripleQuotes,
+            ]),
+        }
+    }
+
+    pub fn html() -> Self {
+        Syntax {
+            language: "HTML",
+            case_sensitive: false,
+            comment: "",
+            comment_multiline: ["<!--", "-->"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::new(),
+            types: BTreeSet::new(),
+            special: BTreeSet::new(),
+            rules: BTreeSet::from([Rule::Markup, Rule::Prefix('&')]),
+        }
+    }
+
+    pub fn xml() -> Self {
+        Syntax::html()
+            .with_language("XML")
+            .with_case_sensitive(true)
+    }
+
+    pub fn css() -> Self {
+        Syntax {
+            language: "CSS",
+            case_sensitive: false,
+            comment: "",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "@charset",
+                "@container",
+                "@import",
+                "@keyframes",
+                "@layer",
+                "@media",
+                "@namespace",
+                "@supports",
+                "and",
+                "not",
+                "only",
+                "or",
+            ]),
+            types: BTreeSet::new(),
+            special: BTreeSet::from([
+                "!important",
+                "auto",
+                "currentcolor",
+                "inherit",
+                "initial",
+                "none",
+                "revert",
+                "transparent",
+                "unset",
+            ]),
+            rules: BTreeSet::from([
+                Rule::Selectors,
+                Rule::Prefix('.'),
+                Rule::Prefix('#'),
+                Rule::Prefix('@'),
+                Rule::Prefix('!'),
+            ]),
+        }
+    }
+}
@@ -1,10 +1,24 @@
 #![allow(dead_code)]
 pub mod asm;
+pub mod build;
+pub mod c;
+pub mod csharp;
+pub mod data;
+pub mod functional;
+pub mod go;
+pub mod java;
+pub mod javascript;
+pub mod kotlin;
 pub mod lua;
+pub mod markup;
 pub mod python;
 pub mod rust;
+pub mod scripting;
+pub mod shader;
 pub mod shell;
 pub mod sql;
+pub mod swift;
+pub mod zig;
 
 use std::collections::BTreeSet;
 use std::hash::{Hash, Hasher};
@@ -18,8 +32,13 @@ type Float = bool;
 #[derive(Default, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum TokenType {
+    Attribute,
+    Column(usize),
     Comment(MultiLine),
+    Emphasis,
     Function,
+    Heading,
+    Key,
     Keyword,
     Literal,
     Hyperlink,
@@ -27,6 +46,7 @@ pub enum TokenType {
     Punctuation(char),
     Special,
     Str(char),
+    Tag,
     Type,
     Whitespace(char),
     #[default]
@@ -36,6 +56,11 @@ impl std::fmt::Debug for TokenType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut name = String::new();
         match &self {
+            TokenType::Attribute => name.push_str("Attribute"),
+            TokenType::Column(column) => {
+                name.push_str("Column ");
+                name.push_str(&column.to_string());
+            }
             TokenType::Comment(multiline) => {
                 name.push_str("Comment");
                 {
@@ -46,7 +71,10 @@ impl std::fmt::Debug for TokenType {
                     }
                 }
             }
+            TokenType::Emphasis => name.push_str("Emphasis"),
             TokenType::Function => name.push_str("Function"),
+            TokenType::Heading => name.push_str("Heading"),
+            TokenType::Key => name.push_str("Key"),
             TokenType::Keyword => name.push_str("Keyword"),
             TokenType::Literal => name.push_str("Literal"),
             TokenType::Hyperlink => name.push_str("Hyperlink"),
@@ -64,6 +92,7 @@ impl std::fmt::Debug for TokenType {
                 name.push_str("Str ");
                 name.push(*quote);
             }
+            TokenType::Tag => name.push_str("Tag"),
             TokenType::Type => name.push_str("Type"),
             TokenType::Whitespace(c) => {
                 name.push_str("Whitespace");
@@ -79,6 +108,39 @@ impl std::fmt::Debug for TokenType {
         write!(f, "{name}")
     }
 }
+impl TokenType {
+    /// CSS class of the token type, as in `tok-keyword`. Columns cycle through six classes.
+    pub const fn class(&self) -> &'static str {
+        const COLUMNS: [&str; 6] = [
+            "tok-column-0",
+            "tok-column-1",
+            "tok-column-2",
+            "tok-column-3",
+            "tok-column-4",
+            "tok-column-5",
+        ];
+        match self {
+            TokenType::Attribute => "tok-attribute",
+            TokenType::Column(column) => COLUMNS[*column % COLUMNS.len()],
+            TokenType::Comment(_) => "tok-comment",
+            TokenType::Emphasis => "tok-emphasis",
+            TokenType::Function => "tok-function",
+            TokenType::Heading => "tok-heading",
+            TokenType::Key => "tok-key",
+            TokenType::Keyword => "tok-keyword",
+            TokenType::Literal => "tok-literal",
+            TokenType::Hyperlink => "tok-hyperlink",
+            TokenType::Numeric(_) => "tok-numeric",
+            TokenType::Punctuation(_) => "tok-punctuation",
+            TokenType::Special => "tok-special",
+            TokenType::Str(_) => "tok-str",
+            TokenType::Tag => "tok-tag",
+            TokenType::Type => "tok-type",
+            TokenType::Whitespace(_) => "tok-whitespace",
+            TokenType::Unknown => "tok-unknown",
+        }
+    }
+}
 impl From<char> for TokenType {
     fn from(c: char) -> Self {
         match c {
@@ -92,6 +154,63 @@ impl From<char> for TokenType {
     }
 }
 
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Optional lexer rules for languages not covered by keywords sets alone.
+pub enum Rule {
+    /// `/pattern/` after an operator or keyword is a `Str('/')`.
+    Regex,
+    /// Name after `<` in expression position is a `Tag`, as in JSX.
+    Jsx,
+    /// Words may start with this char, e.g. `#include` or `@Override`.
+    /// Such words are `Special` unless found in other sets.
+    Prefix(char),
+    /// `"""` opens a string closed only by another `"""`, for any of `QUOTES`.
+    TripleQuotes,
+    /// Words before this separator are a `Key`, e.g. `"name":` or `name =`.
+    Key(char),
+    /// Names in `[section]` header at the start of line are a `Key`.
+    Sections,
+    /// Every field between separators is a `Column`, as in CSV.
+    Columns(char),
+    /// `'` right after a word is an apostrophe, not a string quote, as in `it's`.
+    Apostrophes,
+    /// `'` within or after a name is part of it, as in Haskell `foldl'`.
+    Primes,
+    /// `<name attribute="value">` is a `Tag` with `Attribute`s, as in HTML and XML.
+    /// Quotes outside of tags are plain text.
+    Markup,
+    /// Line starting with this char is a `Heading`, as in Markdown.
+    Headings(char),
+    /// `*text*`, `**text**` and `_text_` within a line are `Emphasis`.
+    Emphasis,
+    /// Only backticks quote strings, so quotes and apostrophes in text are not strings.
+    Prose,
+    /// Words outside of `{}` blocks are a `Tag` and words before `:` inside are
+    /// an `Attribute`, as in CSS. Pseudo-classes like `:hover` are `Special`, as are
+    /// `.class` and `#id` with a `Prefix`. Words before `:` in at-rule parentheses,
+    /// as in `@media (max-width: 10px)`, are a `Key`.
+    Selectors,
+    /// `$name`, `$(name)`, `${name}` and `$@` are `Special`, for this sigil.
+    Variables(char),
+    /// Lines indented with a tab are commands without keys, as in Makefile recipes.
+    Recipes,
+    /// Words with this prefix are a `Type` if the rest of the word is, e.g. `%rax`.
+    Registers(char),
+    /// `'` quotes a single char, so `'a` without a closing quote is `Special`,
+    /// as in OCaml type variables.
+    CharLiterals,
+    /// This char and a name open a string with any bracket or quote, as in `~r/.../` or `%w[...]`.
+    /// Alone it prefixes a name only where an operand is expected, so Julia `:sym` is
+    /// `Special` but `1:n` is not.
+    Sigils(char),
+    /// This char also opens a comment to the end of line, unless `[` follows,
+    /// as in PHP `# comment` and `#[Attribute]`.
+    Comment(char),
+    /// This char twice opens a string closed by the end of line, as in Zig `\\ text`.
+    LineStrings(char),
+}
+
 #[derive(Clone, Debug, PartialEq)]
 /// Rules for highlighting.
 pub struct Syntax {
@@ -103,6 +222,7 @@ pub struct Syntax {
     pub keywords: BTreeSet<&'static str>,
     pub types: BTreeSet<&'static str>,
     pub special: BTreeSet<&'static str>,
+    pub rules: BTreeSet<Rule>,
 }
 impl Default for Syntax {
     fn default() -> Self {
@@ -112,6 +232,14 @@ impl Default for Syntax {
 impl Hash for Syntax {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.language.hash(state);
+        self.case_sensitive.hash(state);
+        self.comment.hash(state);
+        self.comment_multiline.hash(state);
+        self.hyperlinks.hash(state);
+        self.keywords.hash(state);
+        self.types.hash(state);
+        self.special.hash(state);
+        self.rules.hash(state);
     }
 }
 impl Syntax {
@@ -121,6 +249,9 @@ impl Syntax {
             ..Default::default()
         }
     }
+    pub fn with_language(self, language: &'static str) -> Self {
+        Syntax { language, ..self }
+    }
     pub fn with_case_sensitive(self, case_sensitive: bool) -> Self {
         Syntax {
             case_sensitive,
@@ -160,6 +291,73 @@ impl Syntax {
             ..self
         }
     }
+    pub fn with_rules<T: Into<BTreeSet<Rule>>>(self, rules: T) -> Self {
+        Syntax {
+            rules: rules.into(),
+            ..self
+        }
+    }
+
+    pub fn add_hyperlinks<T: IntoIterator<Item = &'static str>>(mut self, hyperlinks: T) -> Self {
+        self.hyperlinks.extend(hyperlinks);
+        self
+    }
+    pub fn add_keywords<T: IntoIterator<Item = &'static str>>(mut self, keywords: T) -> Self {
+        self.keywords.extend(keywords);
+        self
+    }
+    pub fn add_types<T: IntoIterator<Item = &'static str>>(mut self, types: T) -> Self {
+        self.types.extend(types);
+        self
+    }
+    pub fn add_special<T: IntoIterator<Item = &'static str>>(mut self, special: T) -> Self {
+        self.special.extend(special);
+        self
+    }
+    pub fn add_rules<T: IntoIterator<Item = Rule>>(mut self, rules: T) -> Self {
+        self.rules.extend(rules);
+        self
+    }
+    pub fn remove_hyperlinks<'a, T: IntoIterator<Item = &'a str>>(mut self, hyperlinks: T) -> Self {
+        hyperlinks.into_iter().for_each(|word| {
+            self.hyperlinks.remove(word);
+        });
+        self
+    }
+    pub fn remove_keywords<'a, T: IntoIterator<Item = &'a str>>(mut self, keywords: T) -> Self {
+        keywords.into_iter().for_each(|word| {
+            self.keywords.remove(word);
+        });
+        self
+    }
+    pub fn remove_types<'a, T: IntoIterator<Item = &'a str>>(mut self, types: T) -> Self {
+        types.into_iter().for_each(|word| {
+            self.types.remove(word);
+        });
+        self
+    }
+    pub fn remove_special<'a, T: IntoIterator<Item = &'a str>>(mut self, special: T) -> Self {
+        special.into_iter().for_each(|word| {
+            self.special.remove(word);
+        });
+        self
+    }
+    pub fn remove_rules<T: IntoIterator<Item = Rule>>(mut self, rules: T) -> Self {
+        rules.into_iter().for_each(|rule| {
+            self.rules.remove(&rule);
+        });
+        self
+    }
+    /// Extends every words set with the words of `other`.
+    /// Language name, case sensitivity and comments are kept from `self`.
+    pub fn merge(mut self, other: &Syntax) -> Self {
+        self.hyperlinks.extend(&other.hyperlinks);
+        self.keywords.extend(&other.keywords);
+        self.types.extend(&other.types);
+        self.special.extend(&other.special);
+        self.rules.extend(&other.rules);
+        self
+    }
 
     pub fn language(&self) -> &str {
         self.language
@@ -171,24 +369,53 @@ impl Syntax {
         self.hyperlinks.contains(word.to_ascii_lowercase().as_str())
     }
     pub fn is_keyword(&self, word: &str) -> bool {
-        if self.case_sensitive {
-            self.keywords.contains(&word)
-        } else {
-            self.keywords.contains(word.to_ascii_uppercase().as_str())
-        }
+        self.contains(&self.keywords, word)
     }
     pub fn is_type(&self, word: &str) -> bool {
-        if self.case_sensitive {
-            self.types.contains(&word)
-        } else {
-            self.types.contains(word.to_ascii_uppercase().as_str())
-        }
+        let mut chars = word.chars();
+        let register = chars
+            .next()
+            .is_some_and(|p| self.has_rule(Rule::Registers(p)));
+        self.contains(&self.types, word) || register && self.contains(&self.types, chars.as_str())
     }
     pub fn is_special(&self, word: &str) -> bool {
+        self.contains(&self.special, word)
+    }
+    pub fn has_rule(&self, rule: Rule) -> bool {
+        self.rules.contains(&rule)
+    }
+    pub fn has_keys(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| matches!(rule, Rule::Key(_) | Rule::Sections | Rule::Selectors))
+    }
+    pub fn column_separator(&self) -> Option<char> {
+        self.rules.iter().find_map(|rule| match rule {
+            Rule::Columns(separator) => Some(*separator),
+            _ => None,
+        })
+    }
+    pub fn is_prefixed(&self, word: &str) -> bool {
+        let mut chars = word.chars();
+        chars
+            .next()
+            .is_some_and(|p| !p.is_alphanumeric() && self.has_prefix(p))
+            && chars.next().is_some()
+    }
+    pub fn has_prefix(&self, p: char) -> bool {
+        self.has_rule(Rule::Prefix(p))
+            || self.has_rule(Rule::Variables(p))
+            || self.has_rule(Rule::Registers(p))
+            || self.has_rule(Rule::Sigils(p))
+            || p == '\'' && self.has_rule(Rule::CharLiterals)
+    }
+    // Case insensitive sets may be written in upper or lower case, e.g. after merging.
+    fn contains(&self, set: &BTreeSet<&'static str>, word: &str) -> bool {
         if self.case_sensitive {
-            self.special.contains(&word)
+            set.contains(word)
         } else {
-            self.special.contains(word.to_ascii_uppercase().as_str())
+            set.contains(word.to_ascii_uppercase().as_str())
+                || set.contains(word.to_ascii_lowercase().as_str())
         }
     }
 }
@@ -204,6 +431,129 @@ impl Syntax {
             keywords: BTreeSet::new(),
             types: BTreeSet::new(),
             special: BTreeSet::new(),
+            rules: BTreeSet::new(),
         }
     }
 }
+
+// Constructor of built-in syntax with file extensions and whole file names, as in `Makefile`.
+// Language name, constructor and file extensions or names.
+type Builtin = (&'static str, fn() -> Syntax, &'static [&'static str]);
+
+const BUILTIN: [Builtin; 49] = [
+    ("Assembly", Syntax::asm, &["asm", "s"]),
+    ("Assembly (Intel)", Syntax::asm_intel, &["nasm"]),
+    ("Assembly (AT&T)", Syntax::asm_att, &[]),
+    ("Assembly (ARM64)", Syntax::asm_arm64, &[]),
+    ("Assembly (ARMv7 Thumb)", Syntax::asm_thumb, &[]),
+    ("Assembly (RISC-V)", Syntax::asm_riscv, &[]),
+    (
+        "Dockerfile",
+        Syntax::dockerfile,
+        &["dockerfile", "Dockerfile", "Containerfile"],
+    ),
+    (
+        "Makefile",
+        Syntax::makefile,
+        &["mk", "mak", "Makefile", "makefile", "GNUmakefile"],
+    ),
+    ("CMake", Syntax::cmake, &["cmake", "CMakeLists.txt"]),
+    ("Nix", Syntax::nix, &["nix"]),
+    ("HCL", Syntax::hcl, &["hcl", "tf", "tfvars"]),
+    ("C", Syntax::c, &["c", "h"]),
+    (
+        "C++",
+        Syntax::cpp,
+        &["cpp", "cc", "cxx", "hpp", "hh", "hxx"],
+    ),
+    ("C#", Syntax::csharp, &["cs"]),
+    ("JSON", Syntax::json, &["json", "jsonc"]),
+    ("TOML", Syntax::toml, &["toml", "Cargo.lock"]),
+    ("YAML", Syntax::yaml, &["yaml", "yml"]),
+    ("INI", Syntax::ini, &["ini", "cfg", "conf", "properties"]),
+    ("CSV", Syntax::csv, &["csv"]),
+    ("TSV", Syntax::tsv, &["tsv"]),
+    ("Haskell", Syntax::haskell, &["hs", "lhs"]),
+    ("OCaml", Syntax::ocaml, &["ml", "mli"]),
+    ("Elixir", Syntax::elixir, &["ex", "exs"]),
+    ("Go", Syntax::go, &["go"]),
+    ("Java", Syntax::java, &["java"]),
+    (
+        "JavaScript",
+        Syntax::javascript,
+        &["js", "mjs", "cjs", "jsx"],
+    ),
+    (
+        "TypeScript",
+        Syntax::typescript,
+        &["ts", "mts", "cts", "tsx"],
+    ),
+    ("Kotlin", Syntax::kotlin, &["kt", "kts"]),
+    ("Lua", Syntax::lua, &["lua"]),
+    ("Markdown", Syntax::markdown, &["md", "markdown"]),
+    ("HTML", Syntax::html, &["html", "htm"]),
+    (
+        "XML",
+        Syntax::xml,
+        &["xml", "svg", "plist", "xsd", "tmtheme"],
+    ),
+    ("CSS", Syntax::css, &["css"]),
+    ("Python", Syntax::python, &["py", "pyi", "pyw"]),
+    ("Rust", Syntax::rust, &["rs"]),
+    (
+        "Ruby",
+        Syntax::ruby,
+        &["rb", "gemspec", "Gemfile", "Rakefile"],
+    ),
+    ("PHP", Syntax::php, &["php"]),
+    ("R", Syntax::r, &["r"]),
+    ("Julia", Syntax::julia, &["jl"]),
+    (
+        "GLSL",
+        Syntax::glsl,
+        &["glsl", "vert", "frag", "geom", "comp"],
+    ),
+    ("WGSL", Syntax::wgsl, &["wgsl"]),
+    ("HLSL", Syntax::hlsl, &["hlsl", "fx"]),
+    ("Shell", Syntax::shell, &["sh", "bash", "zsh", "ksh"]),
+    ("SQL", Syntax::sql, &["sql"]),
+    ("PostgreSQL", Syntax::postgresql, &["pgsql", "psql"]),
+    ("SQLite", Syntax::sqlite, &[]),
+    ("MySQL", Syntax::mysql, &[]),
+    ("Swift", Syntax::swift, &["swift"]),
+    ("Zig", Syntax::zig, &["zig"]),
+];
+
+impl Syntax {
+    /// Every built-in syntax.
+    pub fn builtin() -> Vec<Syntax> {
+        BUILTIN.iter().map(|(_, syntax, _)| syntax()).collect()
+    }
+
+    /// Built-in syntax by language name, ignoring case, as in `rust` or `c++`.
+    pub fn by_name(name: &str) -> Option<Syntax> {
+        BUILTIN
+            .iter()
+            .find(|(language, _, _)| language.eq_ignore_ascii_case(name))
+            .map(|(_, syntax, _)| syntax())
+    }
+
+    /// Built-in syntax by file extension without the dot, ignoring case.
+    pub fn by_extension(extension: &str) -> Option<Syntax> {
+        BUILTIN
+            .iter()
+            .find(|(_, _, extensions)| extensions.iter().any(|e| e.eq_ignore_ascii_case(extension)))
+            .map(|(_, syntax, _)| syntax())
+    }
+
+    /// Built-in syntax by file name, as in `Makefile`, or else by its extension.
+    pub fn for_path(path: impl AsRef<std::path::Path>) -> Option<Syntax> {
+        let path = path.as_ref();
+        let name = path.file_name()?.to_str()?;
+        BUILTIN
+            .iter()
+            .find(|(_, _, names)| names.contains(&name))
+            .map(|(_, syntax, _)| syntax())
+            .or_else(|| Syntax::by_extension(path.extension()?.to_str()?))
+    }
+}
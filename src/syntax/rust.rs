@@ -83,6 +83,7 @@ impl Syntax {
                 "Weak",
             ]),
             special: BTreeSet::from(["Self", "static", "true", "false"]),
+            rules: BTreeSet::new(),
         }
     }
 }
@@ -0,0 +1,321 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn ruby() -> Self {
+        Syntax {
+            language: "Ruby",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["=begin", "=end"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "BEGIN", "END", "alias", "and", "begin", "break", "case", "class", "def",
+                "defined?", "do", "else", "elsif", "end", "ensure", "for", "if", "in", "module",
+                "next", "not", "or", "redo", "rescue", "retry", "return", "then", "undef",
+                "unless", "until", "when", "while", "yield",
+            ]),
+            types: BTreeSet::from([
+                "Array",
+                "Class",
+                "Comparable",
+                "Enumerable",
+                "Exception",
+                "File",
+                "Float",
+                "Hash",
+                "IO",
+                "Integer",
+                "Kernel",
+                "Module",
+                "NilClass",
+                "Object",
+                "Proc",
+                "Range",
+                "Regexp",
+                "StandardError",
+                "String",
+                "Struct",
+                "Symbol",
+                "Time",
+            ]),
+            special: BTreeSet::from([
+                "__FILE__",
+                "__LINE__",
+                "__dir__",
+                "__method__",
+                "attr_accessor",
+                "attr_reader",
+                "attr_writer",
+                "extend",
+                "false",
+                "include",
+                "lambda",
+                "loop",
+                "nil",
+                "private",
+                "proc",
+                "protected",
+                "public",
+                "puts",
+                "raise",
+                "require",
+                "require_relative",
+                "self",
+                "super",
+                "true",
+            ]),
+            rules: BTreeSet::from([
+                Rule::Prefix(':'),
+                Rule::Prefix('@'),
+                Rule::Prefix('$'),
+                Rule::Sigils('%'),
+            ]),
+        }
+    }
+
+    pub fn php() -> Self {
+        Syntax {
+            language: "PHP",
+            case_sensitive: false,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "abstract",
+                "and",
+                "as",
+                "break",
+                "case",
+                "catch",
+                "class",
+                "clone",
+                "const",
+                "continue",
+                "declare",
+                "default",
+                "do",
+                "echo",
+                "else",
+                "elseif",
+                "enddeclare",
+                "endfor",
+                "endforeach",
+                "endif",
+                "endswitch",
+                "endwhile",
+                "enum",
+                "extends",
+                "final",
+                "finally",
+                "fn",
+                "for",
+                "foreach",
+                "function",
+                "global",
+                "goto",
+                "if",
+                "implements",
+                "include",
+                "include_once",
+                "instanceof",
+                "insteadof",
+                "interface",
+                "match",
+                "namespace",
+                "new",
+                "or",
+                "print",
+                "private",
+                "protected",
+                "public",
+                "readonly",
+                "require",
+                "require_once",
+                "return",
+                "static",
+                "switch",
+                "throw",
+                "trait",
+                "try",
+                "use",
+                "var",
+                "while",
+                "xor",
+                "yield",
+            ]),
+            types: BTreeSet::from([
+                "array", "bool", "callable", "float", "int", "iterable", "mixed", "never",
+                "object", "parent", "self", "string", "void",
+            ]),
+            special: BTreeSet::from([
+                "$this",
+                "__CLASS__",
+                "__DIR__",
+                "__FILE__",
+                "__FUNCTION__",
+                "__LINE__",
+                "__METHOD__",
+                "__NAMESPACE__",
+                "empty",
+                "false",
+                "isset",
+                "list",
+                "null",
+                "php",
+                "true",
+                "unset",
+            ]),
+            rules: BTreeSet::from([Rule::Prefix('$'), Rule::Comment('#')]),
+        }
+    }
+
+    pub fn r() -> Self {
+        Syntax {
+            language: "R",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["#"; 2],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "break", "else", "for", "function", "if", "in", "next", "repeat", "return", "while",
+            ]),
+            types: BTreeSet::from([
+                "character",
+                "complex",
+                "factor",
+                "integer",
+                "list",
+                "logical",
+                "matrix",
+                "numeric",
+                "vector",
+            ]),
+            special: BTreeSet::from([
+                "FALSE",
+                "Inf",
+                "NA",
+                "NA_character_",
+                "NA_integer_",
+                "NA_real_",
+                "NaN",
+                "NULL",
+                "T",
+                "F",
+                "TRUE",
+                "apply",
+                "c",
+                "cat",
+                "lapply",
+                "length",
+                "library",
+                "mean",
+                "names",
+                "paste",
+                "paste0",
+                "print",
+                "require",
+                "sapply",
+                "sum",
+            ]),
+            rules: BTreeSet::new(),
+        }
+    }
+
+    pub fn julia() -> Self {
+        Syntax {
+            language: "Julia",
+            case_sensitive: true,
+            comment: "#",
+            comment_multiline: ["#=", "=#"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "abstract",
+                "baremodule",
+                "begin",
+                "break",
+                "catch",
+                "const",
+                "continue",
+                "do",
+                "else",
+                "elseif",
+                "end",
+                "export",
+                "finally",
+                "for",
+                "function",
+                "global",
+                "if",
+                "import",
+                "in",
+                "let",
+                "local",
+                "macro",
+                "module",
+                "mutable",
+                "primitive",
+                "quote",
+                "return",
+                "struct",
+                "try",
+                "type",
+                "using",
+                "where",
+                "while",
+            ]),
+            types: BTreeSet::from([
+                "AbstractArray",
+                "AbstractFloat",
+                "AbstractString",
+                "Any",
+                "Array",
+                "Bool",
+                "Char",
+                "Complex",
+                "Dict",
+                "Float16",
+                "Float32",
+                "Float64",
+                "Function",
+                "Int",
+                "Int128",
+                "Int16",
+                "Int32",
+                "Int64",
+                "Int8",
+                "Integer",
+                "Matrix",
+                "Nothing",
+                "Number",
+                "Real",
+                "String",
+                "Symbol",
+                "Tuple",
+                "UInt",
+                "UInt16",
+                "UInt32",
+                "UInt64",
+                "UInt8",
+                "Vector",
+            ]),
+            special: BTreeSet::from([
+                "Inf",
+                "NaN",
+                "false",
+                "missing",
+                "nothing",
+                "pi",
+                "print",
+                "println",
+                "true",
+                // Macros
+                "@assert",
+                "@inbounds",
+                "@show",
+                "@time",
+            ]),
+            rules: BTreeSet::from([Rule::Prefix('@'), Rule::Sigils(':'), Rule::Apostrophes]),
+        }
+    }
+}
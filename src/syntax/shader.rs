@@ -0,0 +1,593 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn glsl() -> Self {
+        Syntax {
+            language: "GLSL",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "attribute",
+                "break",
+                "buffer",
+                "case",
+                "centroid",
+                "coherent",
+                "const",
+                "continue",
+                "default",
+                "discard",
+                "do",
+                "else",
+                "flat",
+                "for",
+                "highp",
+                "if",
+                "in",
+                "inout",
+                "invariant",
+                "layout",
+                "lowp",
+                "mediump",
+                "noperspective",
+                "out",
+                "patch",
+                "precise",
+                "precision",
+                "readonly",
+                "restrict",
+                "return",
+                "sample",
+                "shared",
+                "smooth",
+                "struct",
+                "subroutine",
+                "switch",
+                "uniform",
+                "varying",
+                "volatile",
+                "while",
+                "writeonly",
+            ]),
+            types: BTreeSet::from([
+                "void",
+                "bool",
+                "int",
+                "uint",
+                "float",
+                "double",
+                "vec2",
+                "vec3",
+                "vec4",
+                "bvec2",
+                "bvec3",
+                "bvec4",
+                "ivec2",
+                "ivec3",
+                "ivec4",
+                "uvec2",
+                "uvec3",
+                "uvec4",
+                "dvec2",
+                "dvec3",
+                "dvec4",
+                "mat2",
+                "mat3",
+                "mat4",
+                "mat2x2",
+                "mat2x3",
+                "mat2x4",
+                "mat3x2",
+                "mat3x3",
+                "mat3x4",
+                "mat4x2",
+                "mat4x3",
+                "mat4x4",
+                "dmat2",
+                "dmat3",
+                "dmat4",
+                "atomic_uint",
+                "sampler",
+                "sampler1D",
+                "sampler2D",
+                "sampler3D",
+                "samplerCube",
+                "sampler2DArray",
+                "sampler2DShadow",
+                "samplerCubeShadow",
+                "isampler2D",
+                "usampler2D",
+                "texture2D",
+                "image2D",
+                "image3D",
+                "uimage2D",
+            ]),
+            special: BTreeSet::from([
+                "true",
+                "false",
+                // Built-in variables
+                "gl_FragCoord",
+                "gl_FragDepth",
+                "gl_FrontFacing",
+                "gl_GlobalInvocationID",
+                "gl_InstanceID",
+                "gl_InstanceIndex",
+                "gl_LocalInvocationID",
+                "gl_PointCoord",
+                "gl_PointSize",
+                "gl_Position",
+                "gl_VertexID",
+                "gl_VertexIndex",
+                "gl_WorkGroupID",
+                // Layout qualifiers
+                "binding",
+                "local_size_x",
+                "local_size_y",
+                "local_size_z",
+                "location",
+                "push_constant",
+                "set",
+                "std140",
+                "std430",
+                // Built-in functions
+                "abs",
+                "acos",
+                "all",
+                "any",
+                "asin",
+                "atan",
+                "atomicAdd",
+                "barrier",
+                "ceil",
+                "clamp",
+                "cos",
+                "cross",
+                "dFdx",
+                "dFdy",
+                "degrees",
+                "determinant",
+                "distance",
+                "dot",
+                "exp",
+                "exp2",
+                "faceforward",
+                "floor",
+                "fract",
+                "fwidth",
+                "imageLoad",
+                "imageStore",
+                "inverse",
+                "inversesqrt",
+                "length",
+                "log",
+                "log2",
+                "max",
+                "memoryBarrier",
+                "min",
+                "mix",
+                "mod",
+                "normalize",
+                "pow",
+                "radians",
+                "reflect",
+                "refract",
+                "round",
+                "sign",
+                "sin",
+                "smoothstep",
+                "sqrt",
+                "step",
+                "tan",
+                "texelFetch",
+                "texture",
+                "textureLod",
+                "textureSize",
+                "transpose",
+                "trunc",
+                // Preprocessor
+                "#define",
+                "#elif",
+                "#else",
+                "#endif",
+                "#error",
+                "#extension",
+                "#if",
+                "#ifdef",
+                "#ifndef",
+                "#include",
+                "#line",
+                "#pragma",
+                "#undef",
+                "#version",
+            ]),
+            rules: BTreeSet::from([Rule::Prefix('#')]),
+        }
+    }
+
+    pub fn wgsl() -> Self {
+        Syntax {
+            language: "WGSL",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "alias",
+                "break",
+                "case",
+                "const",
+                "const_assert",
+                "continue",
+                "continuing",
+                "default",
+                "diagnostic",
+                "discard",
+                "else",
+                "enable",
+                "fn",
+                "for",
+                "if",
+                "let",
+                "loop",
+                "override",
+                "requires",
+                "return",
+                "struct",
+                "switch",
+                "var",
+                "while",
+            ]),
+            types: BTreeSet::from([
+                "bool",
+                "f16",
+                "f32",
+                "i32",
+                "u32",
+                "vec2",
+                "vec3",
+                "vec4",
+                "vec2f",
+                "vec3f",
+                "vec4f",
+                "vec2i",
+                "vec3i",
+                "vec4i",
+                "vec2u",
+                "vec3u",
+                "vec4u",
+                "vec2h",
+                "vec3h",
+                "vec4h",
+                "mat2x2",
+                "mat3x3",
+                "mat4x4",
+                "mat2x2f",
+                "mat3x3f",
+                "mat4x4f",
+                "array",
+                "atomic",
+                "ptr",
+                "sampler",
+                "sampler_comparison",
+                "texture_1d",
+                "texture_2d",
+                "texture_2d_array",
+                "texture_3d",
+                "texture_cube",
+                "texture_depth_2d",
+                "texture_external",
+                "texture_multisampled_2d",
+                "texture_storage_2d",
+            ]),
+            special: BTreeSet::from([
+                "true",
+                "false",
+                // Attributes
+                "@align",
+                "@binding",
+                "@builtin",
+                "@compute",
+                "@fragment",
+                "@group",
+                "@id",
+                "@interpolate",
+                "@invariant",
+                "@location",
+                "@must_use",
+                "@size",
+                "@vertex",
+                "@workgroup_size",
+                // Address spaces and access modes
+                "function",
+                "private",
+                "read",
+                "read_write",
+                "storage",
+                "uniform",
+                "workgroup",
+                "write",
+                // Built-in values
+                "frag_depth",
+                "front_facing",
+                "global_invocation_id",
+                "instance_index",
+                "local_invocation_id",
+                "num_workgroups",
+                "position",
+                "sample_index",
+                "sample_mask",
+                "vertex_index",
+                "workgroup_id",
+                // Built-in functions
+                "abs",
+                "acos",
+                "all",
+                "any",
+                "arrayLength",
+                "asin",
+                "atan",
+                "atan2",
+                "atomicAdd",
+                "atomicLoad",
+                "atomicStore",
+                "bitcast",
+                "ceil",
+                "clamp",
+                "cos",
+                "cross",
+                "degrees",
+                "determinant",
+                "distance",
+                "dot",
+                "dpdx",
+                "dpdy",
+                "exp",
+                "exp2",
+                "floor",
+                "fract",
+                "fwidth",
+                "inverseSqrt",
+                "length",
+                "log",
+                "log2",
+                "max",
+                "min",
+                "mix",
+                "normalize",
+                "pow",
+                "radians",
+                "reflect",
+                "refract",
+                "round",
+                "select",
+                "sign",
+                "sin",
+                "smoothstep",
+                "sqrt",
+                "step",
+                "storageBarrier",
+                "tan",
+                "textureDimensions",
+                "textureLoad",
+                "textureSample",
+                "textureSampleLevel",
+                "textureStore",
+                "transpose",
+                "trunc",
+                "workgroupBarrier",
+            ]),
+            rules: BTreeSet::from([Rule::Prefix('@')]),
+        }
+    }
+
+    pub fn hlsl() -> Self {
+        Syntax {
+            language: "HLSL",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "break",
+                "case",
+                "cbuffer",
+                "centroid",
+                "class",
+                "column_major",
+                "const",
+                "continue",
+                "default",
+                "discard",
+                "do",
+                "else",
+                "export",
+                "extern",
+                "for",
+                "groupshared",
+                "if",
+                "in",
+                "inline",
+                "inout",
+                "interface",
+                "linear",
+                "namespace",
+                "nointerpolation",
+                "noperspective",
+                "out",
+                "precise",
+                "register",
+                "return",
+                "row_major",
+                "sample",
+                "shared",
+                "static",
+                "struct",
+                "switch",
+                "tbuffer",
+                "typedef",
+                "uniform",
+                "volatile",
+                "while",
+            ]),
+            types: BTreeSet::from([
+                "void",
+                "bool",
+                "int",
+                "uint",
+                "dword",
+                "half",
+                "float",
+                "double",
+                "min16float",
+                "min16int",
+                "min16uint",
+                "bool2",
+                "bool3",
+                "bool4",
+                "int2",
+                "int3",
+                "int4",
+                "uint2",
+                "uint3",
+                "uint4",
+                "half2",
+                "half3",
+                "half4",
+                "float2",
+                "float3",
+                "float4",
+                "double2",
+                "double3",
+                "double4",
+                "float2x2",
+                "float3x3",
+                "float4x3",
+                "float4x4",
+                "matrix",
+                "vector",
+                "Buffer",
+                "ByteAddressBuffer",
+                "ConstantBuffer",
+                "RWBuffer",
+                "RWByteAddressBuffer",
+                "RWStructuredBuffer",
+                "RWTexture2D",
+                "RWTexture3D",
+                "SamplerComparisonState",
+                "SamplerState",
+                "StructuredBuffer",
+                "Texture1D",
+                "Texture2D",
+                "Texture2DArray",
+                "Texture3D",
+                "TextureCube",
+            ]),
+            special: BTreeSet::from([
+                "true",
+                "false",
+                // Semantics
+                "COLOR",
+                "NORMAL",
+                "POSITION",
+                "TANGENT",
+                "TEXCOORD0",
+                "TEXCOORD1",
+                "SV_Depth",
+                "SV_DispatchThreadID",
+                "SV_GroupID",
+                "SV_GroupIndex",
+                "SV_GroupThreadID",
+                "SV_InstanceID",
+                "SV_IsFrontFace",
+                "SV_Position",
+                "SV_PrimitiveID",
+                "SV_Target",
+                "SV_VertexID",
+                // Attributes
+                "branch",
+                "domain",
+                "earlydepthstencil",
+                "flatten",
+                "loop",
+                "maxvertexcount",
+                "numthreads",
+                "outputtopology",
+                "partitioning",
+                "unroll",
+                // Built-in functions
+                "abs",
+                "acos",
+                "all",
+                "any",
+                "asin",
+                "atan",
+                "atan2",
+                "ceil",
+                "clamp",
+                "clip",
+                "cos",
+                "cross",
+                "ddx",
+                "ddy",
+                "degrees",
+                "determinant",
+                "distance",
+                "dot",
+                "exp",
+                "exp2",
+                "floor",
+                "fmod",
+                "frac",
+                "GetDimensions",
+                "GroupMemoryBarrierWithGroupSync",
+                "InterlockedAdd",
+                "length",
+                "lerp",
+                "Load",
+                "log",
+                "log2",
+                "mad",
+                "max",
+                "min",
+                "mul",
+                "normalize",
+                "pow",
+                "radians",
+                "rcp",
+                "reflect",
+                "refract",
+                "round",
+                "rsqrt",
+                "Sample",
+                "SampleCmp",
+                "SampleLevel",
+                "saturate",
+                "sign",
+                "sin",
+                "smoothstep",
+                "sqrt",
+                "step",
+                "tan",
+                "transpose",
+                "trunc",
+                // Preprocessor
+                "#define",
+                "#elif",
+                "#else",
+                "#endif",
+                "#error",
+                "#if",
+                "#ifdef",
+                "#ifndef",
+                "#include",
+                "#line",
+                "#pragma",
+                "#undef",
+            ]),
+            rules: BTreeSet::from([Rule::Prefix('#')]),
+        }
+    }
+}
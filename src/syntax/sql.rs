@@ -133,6 +133,168 @@ impl Syntax {
                 "DATABASE",
             ]),
             special: BTreeSet::from(["PUBLIC"]),
+            rules: BTreeSet::new(),
         }
     }
+
+    pub fn postgresql() -> Self {
+        Syntax::sql()
+            .with_language("PostgreSQL")
+            .remove_keywords(["ROWNUM", "TOP", "TOPDOWN"])
+            .add_keywords([
+                "ANALYZE",
+                "BEGIN",
+                "COMMIT",
+                "CONCURRENTLY",
+                "CONFLICT",
+                "DO",
+                "EXCEPT",
+                "EXPLAIN",
+                "EXTENSION",
+                "FETCH",
+                "FUNCTION",
+                "GRANT",
+                "ILIKE",
+                "INTERSECT",
+                "LANGUAGE",
+                "LATERAL",
+                "MATERIALIZED",
+                "NOTHING",
+                "OFFSET",
+                "ON",
+                "OVER",
+                "PARTITION",
+                "RETURNING",
+                "RETURNS",
+                "REVOKE",
+                "ROLLBACK",
+                "SCHEMA",
+                "SIMILAR",
+                "USING",
+                "VACUUM",
+                "WINDOW",
+            ])
+            .add_types([
+                "BIGSERIAL",
+                "BOOLEAN",
+                "CIDR",
+                "DOUBLE",
+                "HSTORE",
+                "INET",
+                "INTERVAL",
+                "JSON",
+                "JSONB",
+                "MACADDR",
+                "OID",
+                "PRECISION",
+                "SERIAL",
+                "SMALLSERIAL",
+                "TIMESTAMPTZ",
+                "TSQUERY",
+                "TSVECTOR",
+            ])
+            .add_special([
+                "CURRENT_DATE",
+                "CURRENT_TIME",
+                "CURRENT_TIMESTAMP",
+                "CURRENT_USER",
+                "FALSE",
+                "SESSION_USER",
+                "TRUE",
+            ])
+    }
+
+    pub fn sqlite() -> Self {
+        Syntax::sql()
+            .with_language("SQLite")
+            .remove_keywords(["ROWNUM", "TOP", "TOPDOWN"])
+            .add_keywords([
+                "ABORT",
+                "ATTACH",
+                "AUTOINCREMENT",
+                "BEGIN",
+                "COMMIT",
+                "CONFLICT",
+                "DETACH",
+                "ESCAPE",
+                "EXPLAIN",
+                "FAIL",
+                "GLOB",
+                "IF",
+                "IGNORE",
+                "INDEXED",
+                "MATCH",
+                "OFFSET",
+                "ON",
+                "PLAN",
+                "PRAGMA",
+                "QUERY",
+                "REGEXP",
+                "REINDEX",
+                "ROLLBACK",
+                "TEMP",
+                "TEMPORARY",
+                "TRANSACTION",
+                "TRIGGER",
+                "VACUUM",
+                "VIRTUAL",
+                "WITHOUT",
+            ])
+            .add_types(["ANY"])
+            .add_special([
+                "CURRENT_DATE",
+                "CURRENT_TIME",
+                "CURRENT_TIMESTAMP",
+                "FALSE",
+                "ROWID",
+                "TRUE",
+            ])
+    }
+
+    pub fn mysql() -> Self {
+        Syntax::sql()
+            .with_language("MySQL")
+            .remove_keywords(["ROWNUM", "TOP", "TOPDOWN"])
+            .add_keywords([
+                "AUTO_INCREMENT",
+                "BEGIN",
+                "CHARSET",
+                "COLLATE",
+                "COMMIT",
+                "DATABASES",
+                "DESCRIBE",
+                "DUPLICATE",
+                "ENGINE",
+                "IF",
+                "IGNORE",
+                "LOCK",
+                "OFFSET",
+                "ON",
+                "REGEXP",
+                "RLIKE",
+                "ROLLBACK",
+                "SHOW",
+                "STRAIGHT_JOIN",
+                "TABLES",
+                "UNLOCK",
+                "UNSIGNED",
+                "USE",
+                "ZEROFILL",
+            ])
+            .add_types([
+                "BOOLEAN",
+                "DOUBLE",
+                "ENUM",
+                "JSON",
+                "LONGBLOB",
+                "LONGTEXT",
+                "MEDIUMBLOB",
+                "MEDIUMINT",
+                "MEDIUMTEXT",
+                "TINYBLOB",
+                "TINYTEXT",
+                "YEAR",
+            ])
+            .add_special(["CURRENT_TIMESTAMP", "FALSE", "TRUE"])
+    }
 }
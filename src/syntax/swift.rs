@@ -0,0 +1,116 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn swift() -> Self {
+        Syntax {
+            language: "Swift",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: ["/*", "*/"],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                // Declarations
+                "associatedtype",
+                "class",
+                "deinit",
+                "enum",
+                "extension",
+                "fileprivate",
+                "func",
+                "import",
+                "init",
+                "inout",
+                "internal",
+                "let",
+                "open",
+                "operator",
+                "private",
+                "precedencegroup",
+                "protocol",
+                "public",
+                "rethrows",
+                "static",
+                "struct",
+                "subscript",
+                "typealias",
+                "var",
+                // Statements
+                "break",
+                "case",
+                "catch",
+                "continue",
+                "default",
+                "defer",
+                "do",
+                "else",
+                "fallthrough",
+                "for",
+                "guard",
+                "if",
+                "in",
+                "repeat",
+                "return",
+                "switch",
+                "throw",
+                "where",
+                "while",
+                // Expressions
+                "as",
+                "async",
+                "await",
+                "is",
+                "throws",
+                "try",
+                "any",
+                "some",
+                // Context
+                "convenience",
+                "didSet",
+                "dynamic",
+                "final",
+                "get",
+                "indirect",
+                "lazy",
+                "mutating",
+                "nonmutating",
+                "optional",
+                "override",
+                "required",
+                "set",
+                "unowned",
+                "weak",
+                "willSet",
+            ]),
+            types: BTreeSet::from([
+                "Any",
+                "AnyObject",
+                "Array",
+                "Bool",
+                "Character",
+                "Dictionary",
+                "Double",
+                "Error",
+                "Float",
+                "Int",
+                "Int8",
+                "Int16",
+                "Int32",
+                "Int64",
+                "Never",
+                "Optional",
+                "Result",
+                "Set",
+                "String",
+                "UInt",
+                "UInt8",
+                "UInt16",
+                "UInt32",
+                "UInt64",
+                "Void",
+            ]),
+            special: BTreeSet::from(["true", "false", "nil", "self", "Self", "super"]),
+            rules: BTreeSet::from([Rule::Prefix('#'), Rule::Prefix('@'), Rule::TripleQuotes]),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use super::{Rule, Syntax};
+use std::collections::BTreeSet;
+
+impl Syntax {
+    pub fn zig() -> Self {
+        Syntax {
+            language: "Zig",
+            case_sensitive: true,
+            comment: "//",
+            comment_multiline: [""; 2],
+            hyperlinks: BTreeSet::from(["http"]),
+            keywords: BTreeSet::from([
+                "addrspace",
+                "align",
+                "allowzero",
+                "and",
+                "anyframe",
+                "asm",
+                "async",
+                "await",
+                "break",
+                "callconv",
+                "catch",
+                "comptime",
+                "const",
+                "continue",
+                "defer",
+                "else",
+                "enum",
+                "errdefer",
+                "error",
+                "export",
+                "extern",
+                "fn",
+                "for",
+                "if",
+                "inline",
+                "linksection",
+                "noalias",
+                "noinline",
+                "nosuspend",
+                "opaque",
+                "or",
+                "orelse",
+                "packed",
+                "pub",
+                "resume",
+                "return",
+                "struct",
+                "suspend",
+                "switch",
+                "test",
+                "threadlocal",
+                "try",
+                "union",
+                "unreachable",
+                "usingnamespace",
+                "var",
+                "volatile",
+                "while",
+            ]),
+            types: BTreeSet::from([
+                "i8",
+                "u8",
+                "i16",
+                "u16",
+                "i32",
+                "u32",
+                "i64",
+                "u64",
+                "i128",
+                "u128",
+                "isize",
+                "usize",
+                "c_char",
+                "c_short",
+                "c_int",
+                "c_long",
+                "c_longlong",
+                "c_uint",
+                "c_ulong",
+                "f16",
+                "f32",
+                "f64",
+                "f80",
+                "f128",
+                "bool",
+                "anyerror",
+                "anyopaque",
+                "anytype",
+                "comptime_float",
+                "comptime_int",
+                "noreturn",
+                "type",
+                "void",
+            ]),
+            special: BTreeSet::from(["true", "false", "null", "undefined"]),
+            rules: BTreeSet::from([Rule::Prefix('@'), Rule::LineStrings('\\')]),
+        }
+    }
+}
@@ -63,3 +63,1288 @@ fn simple_rust() {
     println!("{str}");
     assert_eq!(input, output);
 }
+
+#[test]
+fn syntax_add_remove_words() {
+    let syntax = Syntax::python()
+        .add_special(["self"])
+        .remove_types(["list"]);
+    assert_eq!(
+        Token::default().tokens(&syntax, "self list"),
+        [
+            Token::new(TokenType::Special, "self"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "list"),
+        ]
+    );
+}
+
+#[test]
+fn syntax_merge() {
+    let custom = Syntax::new("custom").with_keywords(["UPSERT"]);
+    let syntax = Syntax::sql().merge(&custom);
+    assert_eq!(syntax.language(), "SQL");
+    assert!(syntax.is_keyword("upsert"));
+    assert!(syntax.is_keyword("select"));
+}
+
+#[test]
+fn syntax_hash_follows_words() {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+    let hash = |syntax: &Syntax| {
+        let mut hasher = DefaultHasher::new();
+        syntax.hash(&mut hasher);
+        hasher.finish()
+    };
+    let sql = Syntax::sql();
+    assert_eq!(hash(&sql), hash(&Syntax::sql()));
+    assert_ne!(hash(&sql), hash(&sql.clone().add_keywords(["UPSERT"])));
+}
+
+#[test]
+fn sql_dialects() {
+    let postgres = Syntax::postgresql();
+    assert!(postgres.is_keyword("returning"));
+    assert!(postgres.is_type("jsonb"));
+    assert!(!postgres.is_keyword("rownum"));
+    assert!(Syntax::sqlite().is_keyword("pragma"));
+    assert!(Syntax::mysql().is_keyword("auto_increment"));
+    assert!(!Syntax::sql().is_keyword("returning"));
+}
+
+#[test]
+fn asm_dialects() {
+    let att = Syntax::asm_att();
+    assert!(att.is_keyword("movq"));
+    assert!(att.is_type("rax") && att.is_type("%rax"));
+    assert_eq!(
+        Token::default().tokens(&att, "# note"),
+        [Token::new(TokenType::Comment(false), "# note")]
+    );
+    assert!(Syntax::asm_intel().is_type("xmmword"));
+}
+
+#[test]
+fn javascript_regex() {
+    let syntax = Syntax::javascript();
+    assert_eq!(
+        Token::default().tokens(&syntax, "x = /a\\/b+/gi;"),
+        [
+            Token::new(TokenType::Literal, "x"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Str('/'), "/a\\/b+/gi"),
+            Token::new(TokenType::Punctuation(';'), ";"),
+        ]
+    );
+}
+
+#[test]
+fn javascript_division() {
+    let syntax = Syntax::javascript();
+    assert_eq!(
+        Token::default().tokens(&syntax, "a / b // c"),
+        [
+            Token::new(TokenType::Literal, "a"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('/'), "/"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "b"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Comment(false), "// c"),
+        ]
+    );
+}
+
+#[test]
+fn javascript_template_literal() {
+    let syntax = Syntax::javascript();
+    assert_eq!(
+        Token::default().tokens(&syntax, "`a\n${b}`"),
+        [Token::new(TokenType::Str('`'), "`a\n${b}`")]
+    );
+}
+
+#[test]
+fn javascript_jsx() {
+    let syntax = Syntax::javascript();
+    assert_eq!(
+        Token::default().tokens(&syntax, "return <div>{a < b}</div>"),
+        [
+            Token::new(TokenType::Keyword, "return"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('<'), "<"),
+            Token::new(TokenType::Tag, "div"),
+            Token::new(TokenType::Punctuation('>'), ">"),
+            Token::new(TokenType::Punctuation('{'), "{"),
+            Token::new(TokenType::Literal, "a"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('<'), "<"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "b"),
+            Token::new(TokenType::Punctuation('}'), "}"),
+            Token::new(TokenType::Punctuation('<'), "<"),
+            Token::new(TokenType::Punctuation('/'), "/"),
+            Token::new(TokenType::Tag, "div"),
+            Token::new(TokenType::Punctuation('>'), ">"),
+        ]
+    );
+}
+
+#[test]
+fn typescript_types() {
+    let syntax = Syntax::typescript();
+    assert_eq!(
+        Token::default().tokens(&syntax, "let n: number = null"),
+        [
+            Token::new(TokenType::Keyword, "let"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "n"),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Type, "number"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, "null"),
+        ]
+    );
+}
+
+#[test]
+fn c_preprocessor() {
+    let syntax = Syntax::c();
+    assert_eq!(
+        Token::default().tokens(&syntax, "#include <stdio.h>\n#define N 10UL"),
+        [
+            Token::new(TokenType::Special, "#include"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('<'), "<"),
+            Token::new(TokenType::Literal, "stdio"),
+            Token::new(TokenType::Punctuation('.'), "."),
+            Token::new(TokenType::Literal, "h"),
+            Token::new(TokenType::Punctuation('>'), ">"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Special, "#define"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "N"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Numeric(false), "10UL"),
+        ]
+    );
+}
+
+#[test]
+fn c_char_literal_and_suffix() {
+    let syntax = Syntax::c();
+    assert_eq!(
+        Token::default().tokens(&syntax, "char c='\\'';float f=1.0f;0xFFu+1.5e3L"),
+        [
+            Token::new(TokenType::Type, "char"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "c"),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Str('\''), "'\\''"),
+            Token::new(TokenType::Punctuation(';'), ";"),
+            Token::new(TokenType::Type, "float"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "f"),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Numeric(true), "1.0f"),
+            Token::new(TokenType::Punctuation(';'), ";"),
+            Token::new(TokenType::Numeric(false), "0xFFu"),
+            Token::new(TokenType::Punctuation('+'), "+"),
+            Token::new(TokenType::Numeric(true), "1.5e3L"),
+        ]
+    );
+}
+
+#[test]
+fn cpp_and_csharp() {
+    let cpp = Syntax::cpp();
+    assert!(cpp.is_keyword("constexpr"));
+    assert!(cpp.is_special("nullptr"));
+    assert!(cpp.is_special("#include"));
+    let csharp = Syntax::csharp();
+    assert_eq!(
+        Token::default().tokens(&csharp, "#region Main"),
+        [
+            Token::new(TokenType::Special, "#region"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "Main"),
+        ]
+    );
+}
+
+#[test]
+fn triple_quotes() {
+    let syntax = Syntax::kotlin();
+    assert_eq!(
+        Token::default().tokens(&syntax, "\"\"\"a \"b\"\n\"\"\" + \"\""),
+        [
+            Token::new(TokenType::Str('"'), "\"\"\"a \"b\"\n\"\"\""),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('+'), "+"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Str('"'), "\"\""),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&syntax, "\"\";"),
+        [
+            Token::new(TokenType::Str('"'), "\"\""),
+            Token::new(TokenType::Punctuation(';'), ";"),
+        ]
+    );
+}
+
+#[test]
+fn prefixed_words() {
+    let syntax = Syntax::java();
+    assert_eq!(
+        Token::default().tokens(&syntax, "@Override void a"),
+        [
+            Token::new(TokenType::Special, "@Override"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Type, "void"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "a"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::zig(), "@import(x)"),
+        [
+            Token::new(TokenType::Special, "@import"),
+            Token::new(TokenType::Punctuation('('), "("),
+            Token::new(TokenType::Literal, "x"),
+            Token::new(TokenType::Punctuation(')'), ")"),
+        ]
+    );
+}
+
+#[test]
+fn go_raw_string() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::go(), "s := `a\\n\nb`"),
+        [
+            Token::new(TokenType::Literal, "s"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Str('`'), "`a\\n\nb`"),
+        ]
+    );
+}
+
+#[test]
+fn zig_line_strings() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::zig(), "\\\\ a \"b\n\\\\ // c\n;"),
+        [
+            Token::new(TokenType::Str('\n'), "\\\\ a \"b"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Str('\n'), "\\\\ // c"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Punctuation(';'), ";"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::zig(), "a / b // c"),
+        [
+            Token::new(TokenType::Literal, "a"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('/'), "/"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "b"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Comment(false), "// c"),
+        ]
+    );
+}
+
+#[test]
+fn json_keys() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::json(), "{\"a\": [\"b\", null]}"),
+        [
+            Token::new(TokenType::Punctuation('{'), "{"),
+            Token::new(TokenType::Key, "\"a\""),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('['), "["),
+            Token::new(TokenType::Str('"'), "\"b\""),
+            Token::new(TokenType::Punctuation(','), ","),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, "null"),
+            Token::new(TokenType::Punctuation(']'), "]"),
+            Token::new(TokenType::Punctuation('}'), "}"),
+        ]
+    );
+}
+
+#[test]
+fn toml_sections_and_keys() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::toml(), "[server]\nsite.host = true"),
+        [
+            Token::new(TokenType::Punctuation('['), "["),
+            Token::new(TokenType::Key, "server"),
+            Token::new(TokenType::Punctuation(']'), "]"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Key, "site"),
+            Token::new(TokenType::Punctuation('.'), "."),
+            Token::new(TokenType::Key, "host"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, "true"),
+        ]
+    );
+}
+
+#[test]
+fn yaml_keys() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::yaml(), "- name: it's\n  x"),
+        [
+            Token::new(TokenType::Punctuation('-'), "-"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Key, "name"),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "it"),
+            Token::new(TokenType::Punctuation('\''), "'"),
+            Token::new(TokenType::Literal, "s"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "x"),
+        ]
+    );
+}
+
+#[test]
+fn csv_columns() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::csv(), "a,\"b,c\"\n1,2"),
+        [
+            Token::new(TokenType::Column(0), "a"),
+            Token::new(TokenType::Punctuation(','), ","),
+            Token::new(TokenType::Column(1), "\"b,c\""),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Column(0), "1"),
+            Token::new(TokenType::Punctuation(','), ","),
+            Token::new(TokenType::Column(1), "2"),
+        ]
+    );
+}
+
+#[test]
+fn markdown_headings_and_emphasis() {
+    assert_eq!(
+        Token::default().tokens(
+            &Syntax::markdown(),
+            "# Title\nIt's *so* `a_b` snake_case\n* x"
+        ),
+        [
+            Token::new(TokenType::Heading, "# Title"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Literal, "It"),
+            Token::new(TokenType::Punctuation('\''), "'"),
+            Token::new(TokenType::Literal, "s"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Emphasis, "*so*"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Str('`'), "`a_b`"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "snake_case"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Punctuation('*'), "*"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "x"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::markdown(), "a **b c** #1"),
+        [
+            Token::new(TokenType::Literal, "a"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Emphasis, "**b c**"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('#'), "#"),
+            Token::new(TokenType::Numeric(false), "1"),
+        ]
+    );
+}
+
+#[test]
+fn html_tags_and_attributes() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::html(), "<a href=\"x\">Don't &amp;</a><!-- c -->"),
+        [
+            Token::new(TokenType::Punctuation('<'), "<"),
+            Token::new(TokenType::Tag, "a"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Attribute, "href"),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Str('"'), "\"x\""),
+            Token::new(TokenType::Punctuation('>'), ">"),
+            Token::new(TokenType::Literal, "Don"),
+            Token::new(TokenType::Punctuation('\''), "'"),
+            Token::new(TokenType::Literal, "t"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, "&amp"),
+            Token::new(TokenType::Punctuation(';'), ";"),
+            Token::new(TokenType::Punctuation('<'), "<"),
+            Token::new(TokenType::Punctuation('/'), "/"),
+            Token::new(TokenType::Tag, "a"),
+            Token::new(TokenType::Punctuation('>'), ">"),
+            Token::new(TokenType::Comment(true), "<!-- c -->"),
+        ]
+    );
+}
+
+#[test]
+fn css_selectors_and_properties() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::css(), "p .x { font-size: 2em; }"),
+        [
+            Token::new(TokenType::Tag, "p"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, ".x"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('{'), "{"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Attribute, "font"),
+            Token::new(TokenType::Punctuation('-'), "-"),
+            Token::new(TokenType::Attribute, "size"),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Numeric(false), "2em"),
+            Token::new(TokenType::Punctuation(';'), ";"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('}'), "}"),
+        ]
+    );
+}
+
+#[test]
+fn css_at_rules_and_pseudo_selectors() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::css(), "@media (max-width: 10px) {"),
+        [
+            Token::new(TokenType::Keyword, "@media"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('('), "("),
+            Token::new(TokenType::Key, "max"),
+            Token::new(TokenType::Punctuation('-'), "-"),
+            Token::new(TokenType::Key, "width"),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Numeric(false), "10px"),
+            Token::new(TokenType::Punctuation(')'), ")"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('{'), "{"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::css(), "a:hover, #b::before, .c {}"),
+        [
+            Token::new(TokenType::Tag, "a"),
+            Token::new(TokenType::Special, ":"),
+            Token::new(TokenType::Special, "hover"),
+            Token::new(TokenType::Punctuation(','), ","),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, "#b"),
+            Token::new(TokenType::Special, ":"),
+            Token::new(TokenType::Special, ":"),
+            Token::new(TokenType::Special, "before"),
+            Token::new(TokenType::Punctuation(','), ","),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, ".c"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('{'), "{"),
+            Token::new(TokenType::Punctuation('}'), "}"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::css(), "a{color:red}"),
+        [
+            Token::new(TokenType::Tag, "a"),
+            Token::new(TokenType::Punctuation('{'), "{"),
+            Token::new(TokenType::Attribute, "color"),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Literal, "red"),
+            Token::new(TokenType::Punctuation('}'), "}"),
+        ]
+    );
+}
+
+#[test]
+fn shader_builtins() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::wgsl(), "@vertex fn f(p: vec4f)"),
+        [
+            Token::new(TokenType::Special, "@vertex"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Keyword, "fn"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Function, "f"),
+            Token::new(TokenType::Punctuation('('), "("),
+            Token::new(TokenType::Literal, "p"),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Type, "vec4f"),
+            Token::new(TokenType::Punctuation(')'), ")"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::glsl(), "layout(location = 0) out vec3"),
+        [
+            Token::new(TokenType::Keyword, "layout"),
+            Token::new(TokenType::Punctuation('('), "("),
+            Token::new(TokenType::Special, "location"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Numeric(false), "0"),
+            Token::new(TokenType::Punctuation(')'), ")"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Keyword, "out"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Type, "vec3"),
+        ]
+    );
+    let hlsl = Syntax::hlsl();
+    assert!(hlsl.is_type("float4") && hlsl.is_special("SV_Position") && hlsl.is_special("mul"));
+}
+
+#[test]
+fn makefile_targets_and_variables() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::makefile(), "all: $(OBJ)\n\tcc -o $@ a=b"),
+        [
+            Token::new(TokenType::Key, "all"),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, "$(OBJ)"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Whitespace('\t'), "\t"),
+            Token::new(TokenType::Literal, "cc"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('-'), "-"),
+            Token::new(TokenType::Literal, "o"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, "$@"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "a"),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Literal, "b"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::nix(), "x = a == b;"),
+        [
+            Token::new(TokenType::Key, "x"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "a"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Punctuation('='), "="),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "b"),
+            Token::new(TokenType::Punctuation(';'), ";"),
+        ]
+    );
+}
+
+#[test]
+fn asm_registers_directives_and_labels() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::asm_arm64(), ".globl f\nf: ldr x0, [sp]"),
+        [
+            Token::new(TokenType::Special, ".globl"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "f"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Key, "f"),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Keyword, "ldr"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Type, "x0"),
+            Token::new(TokenType::Punctuation(','), ","),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('['), "["),
+            Token::new(TokenType::Type, "sp"),
+            Token::new(TokenType::Punctuation(']'), "]"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::asm_att(), "movq $1, %rax"),
+        [
+            Token::new(TokenType::Keyword, "movq"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Punctuation('$'), "$"),
+            Token::new(TokenType::Numeric(false), "1"),
+            Token::new(TokenType::Punctuation(','), ","),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Type, "%rax"),
+        ]
+    );
+    let riscv = Syntax::asm_riscv();
+    assert!(riscv.is_keyword("addi") && riscv.is_type("a0") && riscv.is_special("%hi"));
+    let thumb = Syntax::asm_thumb();
+    assert!(thumb.is_keyword("push") && thumb.is_type("r7") && thumb.comment() == "@");
+}
+
+#[test]
+fn functional_comments_atoms_and_sigils() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::julia(), "#= a =# x # b"),
+        [
+            Token::new(TokenType::Comment(true), "#= a =#"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "x"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Comment(false), "# b"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::julia(), "f(:a, 1:n)"),
+        [
+            Token::new(TokenType::Function, "f"),
+            Token::new(TokenType::Punctuation('('), "("),
+            Token::new(TokenType::Special, ":a"),
+            Token::new(TokenType::Punctuation(','), ","),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Numeric(false), "1"),
+            Token::new(TokenType::Punctuation(':'), ":"),
+            Token::new(TokenType::Literal, "n"),
+            Token::new(TokenType::Punctuation(')'), ")"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::haskell(), "foldl' x'"),
+        [
+            Token::new(TokenType::Literal, "foldl'"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Literal, "x'"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::ruby(), "=begin\na\n=end\n:ok"),
+        [
+            Token::new(TokenType::Comment(true), "=begin\na\n=end"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Special, ":ok"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::elixir(), "~w(a b) :ok"),
+        [
+            Token::new(TokenType::Str(')'), "~w(a b)"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, ":ok"),
+        ]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::elixir(), "~r/a+/i"),
+        [Token::new(TokenType::Str('/'), "~r/a+/i")]
+    );
+    assert_eq!(
+        Token::default().tokens(&Syntax::ocaml(), "(* a *) 'a 'b'"),
+        [
+            Token::new(TokenType::Comment(true), "(* a *)"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Special, "'a"),
+            Token::new(TokenType::Whitespace(' '), " "),
+            Token::new(TokenType::Str('\''), "'b'"),
+        ]
+    );
+}
+
+#[test]
+fn php_comments_and_attributes() {
+    assert_eq!(
+        Token::default().tokens(&Syntax::php(), "#[Pure]\n$a# b\n// c\n#"),
+        [
+            Token::new(TokenType::Punctuation('#'), "#"),
+            Token::new(TokenType::Punctuation('['), "["),
+            Token::new(TokenType::Literal, "Pure"),
+            Token::new(TokenType::Punctuation(']'), "]"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Special, "$a"),
+            Token::new(TokenType::Comment(false), "# b"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Comment(false), "// c"),
+            Token::new(TokenType::Whitespace('\n'), "\n"),
+            Token::new(TokenType::Comment(false), "#"),
+        ]
+    );
+}
+
+#[test]
+fn theme_font_styles() {
+    assert!(ColorTheme::AYU.type_style(TokenType::Comment(false)).italic);
+    assert!(
+        ColorTheme::GITHUB_DARK
+            .type_style(TokenType::Comment(false))
+            .is_plain()
+    );
+    assert!(ColorTheme::GITHUB_DARK.type_style(TokenType::Heading).bold);
+    assert!(
+        ColorTheme::GITHUB_DARK
+            .type_style(TokenType::Hyperlink)
+            .underline
+    );
+    assert!(ColorTheme::GRUVBOX.type_style(TokenType::Function).bold);
+    assert!(ColorTheme::SONOKAI.type_style(TokenType::Attribute).italic);
+    let plain_links = ColorTheme {
+        styles: ThemeStyles {
+            hyperlinks: FontStyle::NONE,
+            ..ColorTheme::AYU.styles
+        },
+        ..ColorTheme::AYU
+    };
+    assert!(plain_links.type_style(TokenType::Hyperlink).is_plain());
+    #[cfg(feature = "egui")]
+    assert!(format_token(&ColorTheme::GRUVBOX, 14.0, TokenType::Comment(true)).italics);
+}
+
+#[test]
+fn hex_colors() {
+    assert_eq!(HexColor::parse("#0D1117"), Some(HexColor::rgb(13, 17, 23)));
+    assert_eq!(
+        HexColor::parse("#0d111780"),
+        Some(HexColor::rgba(13, 17, 23, 128))
+    );
+    assert_eq!(HexColor::parse("none"), Some(HexColor::NONE));
+    assert_eq!(
+        HexColor::parse("#0d11"),
+        Some(HexColor::rgba(0, 0xdd, 0x11, 0x11))
+    );
+    assert_eq!(HexColor::parse("#0d111"), None);
+    assert_eq!(HexColor::parse("#0d111g"), None);
+    assert_eq!(HexColor::rgb(13, 17, 23).to_string(), "#0d1117");
+    assert_eq!(HexColor::rgba(13, 17, 23, 128).to_string(), "#0d111780");
+    assert_eq!("none".parse(), Ok(HexColor::NONE));
+}
+
+#[test]
+#[allow(deprecated)]
+fn theme_color_str() {
+    let theme = ColorTheme::AYU;
+    assert_eq!(theme.type_color_str(TokenType::Keyword), "#fa8d3e");
+    assert_eq!(
+        theme.column_color_str(7),
+        theme.type_color_str(TokenType::Function)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn theme_serde_round_trip() {
+    for theme in DEFAULT_THEMES {
+        let json = serde_json::to_string(&theme).unwrap();
+        assert_eq!(serde_json::from_str::<ColorTheme>(&json).unwrap(), theme);
+        let toml = toml::to_string(&theme).unwrap();
+        assert_eq!(toml::from_str::<ColorTheme>(&toml).unwrap(), theme);
+    }
+    let custom = ColorTheme {
+        bg: HexColor::hex("#101010"),
+        ..ColorTheme::AYU.with_name("Custom")
+    };
+    let toml = toml::to_string(&custom).unwrap();
+    assert!(toml.contains("name = \"Custom\"") && toml.contains("bg = \"#101010\""));
+}
+
+#[test]
+fn theme_import() {
+    let vscode = ColorTheme::from_vscode(
+        r##"{
+            // Comments and trailing commas are allowed
+            "name": "Code",
+            "type": "light",
+            "colors": { "editor.background": "#fff", "editor.foreground": "#333333", },
+            "tokenColors": [
+                { "scope": "comment", "settings": { "foreground": "#008000", "fontStyle": "italic" } },
+                { "scope": ["keyword", "storage"], "settings": { "foreground": "#0000ff" } },
+                { "scope": "keyword.operator", "settings": { "foreground": "#000000" } },
+            ],
+        }"##,
+    )
+    .unwrap();
+    assert_eq!(vscode.name(), "Code");
+    assert!(!vscode.is_dark());
+    assert_eq!(vscode.bg, HexColor::rgb(255, 255, 255));
+    assert_eq!(vscode.comments, HexColor::rgb(0, 128, 0));
+    assert!(vscode.styles.comments.italic);
+    assert_eq!(vscode.keywords, HexColor::rgb(0, 0, 255));
+    assert_eq!(vscode.punctuation, HexColor::rgb(0, 0, 0));
+    assert_eq!(vscode.strs, HexColor::rgb(51, 51, 51));
+
+    let tmtheme = ColorTheme::from_tmtheme(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+        <plist version="1.0"><dict>
+            <key>name</key><string>Mac</string>
+            <key>settings</key><array>
+                <dict><key>settings</key><dict>
+                    <key>background</key><string>#272822</string>
+                    <key>caret</key><string>#F8F8F0</string>
+                </dict></dict>
+                <dict>
+                    <key>scope</key><string>string, constant.numeric</string>
+                    <key>settings</key><dict><key>foreground</key><string>#E6DB74</string></dict>
+                </dict>
+            </array>
+        </dict></plist>"#,
+    )
+    .unwrap();
+    assert!(tmtheme.is_dark());
+    assert_eq!(tmtheme.cursor, HexColor::rgb(248, 248, 240));
+    assert_eq!(tmtheme.strs, HexColor::rgb(230, 219, 116));
+    assert_eq!(tmtheme.numerics, tmtheme.strs);
+
+    let palette = (0..16)
+        .map(|i| format!("base0{i:X}: \"{:02x}{:02x}{:02x}\" # comment\n", i, i, i))
+        .collect::<String>();
+    let base16 = ColorTheme::from_base16(&format!("scheme: \"Grey\"\n{palette}")).unwrap();
+    assert_eq!(base16.name(), "Grey");
+    assert_eq!(base16.keywords, HexColor::rgb(14, 14, 14));
+    assert_eq!(base16.chrome.error, base16.tags);
+    assert_eq!(base16.chrome.info, base16.functions);
+    let malformed = format!("{palette}base010: \"ffffff\"\n");
+    assert!(matches!(
+        ColorTheme::from_base16(&malformed),
+        Err(ImportError::Theme(ThemeError::UnknownField(key))) if key == "base010"
+    ));
+    assert!(matches!(
+        ColorTheme::from_base16("base00: \"000000\""),
+        Err(ImportError::MissingColor(key)) if key == "base01"
+    ));
+    assert!(ColorTheme::from_base16(&format!("{palette}base18: \"ffffff\"\n")).is_err());
+    let base24 = ColorTheme::from_base16(&format!("{palette}base12: \"ff0000\"\n")).unwrap();
+    assert_eq!(base24.chrome.error, HexColor::rgb(255, 0, 0));
+}
+
+#[test]
+fn theme_chrome_fallbacks() {
+    let gruvbox = ColorTheme::GRUVBOX.chrome_colors();
+    assert_eq!(gruvbox.active_line_number, HexColor::hex("#fabd2f"));
+    let mono = ColorTheme::monocolor(
+        true,
+        HexColor::hex("#000000"),
+        HexColor::hex("#ffffff"),
+        HexColor::hex("#ffffff"),
+        HexColor::hex("#808080"),
+    )
+    .chrome_colors();
+    assert_eq!(mono.gutter, HexColor::hex("#000000"));
+    assert_eq!(mono.line_numbers, HexColor::hex("#ffffff"));
+    assert_eq!(mono.current_line, HexColor::hex("#000000"));
+    for theme in DEFAULT_THEMES {
+        assert_eq!(theme.chrome_colors(), theme.chrome, "{}", theme.name());
+    }
+}
+
+#[test]
+fn css_colors_and_validation() {
+    let parse = |css: &str| css.parse::<HexColor>();
+    assert_eq!(parse("#abc"), Ok(HexColor::rgb(0xaa, 0xbb, 0xcc)));
+    assert_eq!(parse("#abcd"), Ok(HexColor::rgba(0xaa, 0xbb, 0xcc, 0xdd)));
+    assert_eq!(parse("rgb(255, 128, 0)"), Ok(HexColor::rgb(255, 128, 0)));
+    assert_eq!(
+        parse("rgba(100% 0% 0% / 50%)"),
+        Ok(HexColor::rgba(255, 0, 0, 128))
+    );
+    assert_eq!(
+        parse("hsl(120deg, 100%, 25%)"),
+        Ok(HexColor::rgb(0, 128, 0))
+    );
+    assert_eq!(
+        parse("hsla(0, 0%, 100%, 0.5)"),
+        Ok(HexColor::rgba(255, 255, 255, 128))
+    );
+    assert_eq!(parse("RebeccaPurple"), Ok(HexColor::rgb(0x66, 0x33, 0x99)));
+    assert_eq!(parse("transparent"), Ok(HexColor::NONE));
+    assert!(parse("#abcde").is_err() && parse("rgb(1, 2)").is_err() && parse("bluish").is_err());
+
+    let theme = ColorTheme::GRUVBOX
+        .with_colors([("bg", "black"), ("chrome.gutter", "#111")])
+        .unwrap();
+    assert_eq!(theme.bg, HexColor::rgb(0, 0, 0));
+    assert_eq!(theme.chrome.gutter, HexColor::rgb(17, 17, 17));
+    assert!(
+        theme
+            .colors()
+            .contains(&("chrome.gutter", HexColor::rgb(17, 17, 17)))
+    );
+    assert_eq!(
+        ColorTheme::GRUVBOX.with_colors([("strs", "#12345"), ("fg", "white"), ("types", "red")]),
+        Err(vec![
+            ThemeError::MalformedColor {
+                field: String::from("strs"),
+                value: String::from("#12345")
+            },
+            ThemeError::UnknownField(String::from("fg")),
+        ])
+    );
+}
+
+#[test]
+fn theme_contrast() {
+    let (white, black) = (HexColor::rgb(255, 255, 255), HexColor::rgb(0, 0, 0));
+    assert!((white.contrast(black) - 21.0).abs() < 0.01);
+    assert_eq!(black.contrast(black), 1.0);
+    assert_eq!(black.mix(white, 0.5), HexColor::rgb(128, 128, 128));
+    let mirage = ColorTheme::AYU_MIRAGE;
+    let failures = mirage.contrast_failures(ColorTheme::CONTRAST_AA);
+    assert!(
+        failures
+            .iter()
+            .any(|(field, ratio)| *field == "comments" && *ratio < 2.5)
+    );
+    assert_eq!(mirage.contrast_ratios().len(), 13);
+    for theme in DEFAULT_THEMES {
+        let adjusted = theme.clone().with_contrast(ColorTheme::CONTRAST_AA);
+        assert_eq!(adjusted.contrast_failures(ColorTheme::CONTRAST_AA), []);
+        assert_eq!((adjusted.bg, adjusted.chrome), (theme.bg, theme.chrome));
+        // Passing colors are kept
+        if theme.contrast_failures(ColorTheme::CONTRAST_AA).is_empty() {
+            assert_eq!(adjusted, theme);
+        }
+    }
+    for theme in [
+        ColorTheme::HIGH_CONTRAST_DARK,
+        ColorTheme::HIGH_CONTRAST_LIGHT,
+    ] {
+        assert_eq!(
+            theme.contrast_failures(ColorTheme::CONTRAST_AAA),
+            [],
+            "{}",
+            theme.name()
+        );
+    }
+}
+
+#[test]
+fn theme_variants() {
+    let white = HexColor::rgb(255, 255, 255);
+    assert_eq!(white.invert_lightness(), HexColor::rgb(0, 0, 0));
+    assert_eq!(HexColor::NONE.invert_lightness(), HexColor::NONE);
+    let gray = HexColor::rgb(119, 119, 119);
+    assert_eq!(gray.invert_lightness().invert_lightness(), gray);
+    let sonokai = ColorTheme::SONOKAI;
+    let light = sonokai.to_light();
+    assert_eq!((light.name(), light.dark), ("Sonokai Light", false));
+    assert!(light.bg.luminance() > light.keywords.luminance());
+    assert!(light.keywords.luminance() < sonokai.keywords.luminance());
+    assert_eq!(light.to_light(), light);
+    assert_eq!(ColorTheme::AYU_DARK.to_light().name(), "Ayu Light");
+    assert_eq!(light.to_dark().name(), "Sonokai Dark");
+}
+
+#[cfg(feature = "egui")]
+#[test]
+fn theme_from_style() {
+    for visuals in [egui::Visuals::dark(), egui::Visuals::light()] {
+        let style = egui::Style {
+            visuals: visuals.clone(),
+            ..Default::default()
+        };
+        let theme = ColorTheme::from_style(&style);
+        assert_eq!(theme.dark, visuals.dark_mode);
+        assert_eq!(theme.bg, HexColor::from(visuals.text_edit_bg_color()));
+        assert_eq!(theme.contrast_failures(ColorTheme::CONTRAST_AA), []);
+    }
+}
+
+#[test]
+fn theme_export() {
+    // Generated constant matches the hand written one, comments aside
+    let github = include_str!("themes/github.rs");
+    let start = github.find("pub const GITHUB_DARK").unwrap();
+    let end = start + github[start..].find("};").unwrap() + 2;
+    let strip = |source: &str| {
+        source
+            .lines()
+            .map(|line| line.split(" //").next().unwrap().trim())
+            .collect::<String>()
+    };
+    assert_eq!(
+        strip(&ColorTheme::GITHUB_DARK.to_rust("GITHUB_DARK")),
+        strip(&github[start..end])
+    );
+    let mono = ColorTheme::monocolor(
+        true,
+        HexColor::hex("#000000"),
+        HexColor::hex("#ffffff"),
+        HexColor::hex("#ffffff"),
+        HexColor::hex("#808080"),
+    );
+    let rust = mono.to_rust("MONO");
+    assert!(rust.contains("    styles: ThemeStyles::NONE,\n    chrome: ChromeColors::NONE,\n"));
+    assert!(
+        ColorTheme::HIGH_CONTRAST_DARK
+            .to_rust("HIGH_CONTRAST_DARK")
+            .contains("styles: ThemeStyles::CLASSIC,")
+    );
+    assert!(
+        ColorTheme::GRUVBOX
+            .to_rust("GRUVBOX")
+            .contains("        functions: FontStyle::BOLD,\n")
+    );
+    let styled = ColorTheme {
+        styles: ThemeStyles {
+            strs: FontStyle::NONE
+                .underline()
+                .with_background(HexColor::hex("#10203040")),
+            ..ThemeStyles::CLASSIC
+        },
+        ..ColorTheme::SONOKAI
+    };
+    assert!(styled.to_rust("STYLED").contains(
+        "strs: FontStyle::NONE.underline().with_background(HexColor::hex(\"#10203040\")),"
+    ));
+    assert!(
+        styled
+            .to_toml()
+            .contains("[styles.strs]\nunderline = true\nbackground = \"#10203040\"\n")
+    );
+    let awkward = ColorTheme::AYU.with_name("\"a\" \\ b\u{1}\n\tc\u{7f} é");
+    assert!(
+        awkward
+            .to_json()
+            .contains("\"name\": \"\\\"a\\\" \\\\ b\\u0001\\n\\tc\\u007f é\",")
+    );
+    #[cfg(feature = "serde")]
+    for theme in DEFAULT_THEMES.into_iter().chain([mono, styled, awkward]) {
+        assert_eq!(
+            toml::from_str::<ColorTheme>(&theme.to_toml()).unwrap(),
+            theme
+        );
+        assert_eq!(
+            serde_json::from_str::<ColorTheme>(&theme.to_json()).unwrap(),
+            theme
+        );
+    }
+}
+
+#[test]
+fn theme_css() {
+    assert_eq!(TokenType::Str('"').class(), "tok-str");
+    assert_eq!(TokenType::Column(7).class(), "tok-column-1");
+    let css = ColorTheme::AYU_MIRAGE.to_css(".code");
+    assert!(css.starts_with(".code { color: #cccac2; background-color: #1f2430; }\n"));
+    assert!(css.contains(".code .tok-keyword { color: #ffad66; }\n"));
+    assert!(css.contains(
+        ".code .tok-line-highlighted { display: inline-block; width: 100%; background-color: #1a1f29; }\n"
+    ));
+    assert!(css.contains(".code .tok-comment { color: #565b66; font-style: italic; }\n"));
+    assert!(css.contains(".code .tok-hyperlink { color: #f28779; text-decoration: underline; }\n"));
+    let adaptive = ColorTheme::GITHUB_LIGHT.to_css_with(&ColorTheme::GITHUB_DARK, "pre");
+    let (light, dark) = adaptive
+        .split_once("@media (prefers-color-scheme: dark) {\n")
+        .unwrap();
+    assert!(light.contains("pre .tok-str { color: #0a3069; }"));
+    assert!(dark.contains("  pre .tok-str { color: #a5d6ff; }\n") && dark.ends_with("}\n"));
+}
+
+#[test]
+fn html_render() {
+    let code = "/* a\n<b> */\nfn x() {}\n";
+    let html = HtmlRenderer::default()
+        .with_theme(ColorTheme::GITHUB_LIGHT)
+        .render(code);
+    assert_eq!(
+        html,
+        "<pre class=\"code\" style=\"color: #24292f; background-color: #ffffff;\"><code>\
+<span style=\"color: #57606a;\">/* a</span>\n\
+<span style=\"color: #57606a;\">&lt;b&gt; */</span>\n\
+<span style=\"color: #cf222e;\">fn</span> <span style=\"color: #8250df;\">x</span>\
+<span style=\"color: #24292f;\">(</span><span style=\"color: #24292f;\">)</span> \
+<span style=\"color: #24292f;\">{</span><span style=\"color: #24292f;\">}</span>\n\
+</code></pre>\n"
+    );
+    let html = HtmlRenderer::default()
+        .with_classes(true)
+        .with_numlines(true)
+        .with_numlines_shift(8)
+        .with_anchors("L")
+        .highlight_lines(10..=10)
+        .render(code);
+    assert!(html.starts_with("<pre class=\"code\"><code><span id=\"L9\">"));
+    assert!(html.contains(
+        "<span class=\"tok-line-number\"> 9 </span><span class=\"tok-comment\">/* a</span></span>\n"
+    ));
+    assert!(html.contains(
+        "<span id=\"L10\" class=\"tok-line-highlighted\"><span class=\"tok-line-number\">10 </span>"
+    ));
+    assert!(
+        ColorTheme::GRUVBOX
+            .to_css("pre.code")
+            .contains("pre.code .tok-line-highlighted {")
+    );
+}
+
+#[test]
+fn ansi_render() {
+    let render = |depth| {
+        AnsiRenderer::default()
+            .with_theme(ColorTheme::GITHUB_LIGHT)
+            .with_depth(depth)
+            .render("fn x\n")
+    };
+    assert_eq!(render(ColorDepth::Plain), "fn x\n");
+    assert_eq!(
+        render(ColorDepth::TrueColor),
+        "\x1b[0;38;2;207;34;46mfn \x1b[0;38;2;36;41;47mx\x1b[0m\n"
+    );
+    assert_eq!(
+        render(ColorDepth::Ansi256),
+        "\x1b[0;38;5;160mfn \x1b[0;38;5;235mx\x1b[0m\n"
+    );
+    assert_eq!(
+        render(ColorDepth::Ansi16),
+        "\x1b[0;31mfn \x1b[0;30mx\x1b[0m\n"
+    );
+    let gutter = AnsiRenderer::default()
+        .with_depth(ColorDepth::Plain)
+        .with_numlines(true)
+        .with_numlines_shift(8)
+        .with_gutter(true)
+        .render("a\nb\n");
+    assert_eq!(gutter, " 9 │ a\n10 │ b\n");
+}
+
+#[test]
+fn document_render() {
+    let latex = LatexRenderer::default().render("fn f(s: &str) {}\n");
+    assert!(latex.starts_with("\\begin{Verbatim}[commandchars=\\\\\\{\\},"));
+    assert!(latex.contains("\\textcolor[HTML]{CF222E}{fn} "));
+    assert!(latex.contains("\\textcolor[HTML]{24292F}{\\{}"));
+    assert!(latex.ends_with("\n\\end{Verbatim}\n"));
+
+    let rtf = RtfRenderer::default()
+        .with_numlines(true)
+        .render("fn x\n\"é\"\n");
+    assert!(rtf.starts_with("{\\rtf1\\ansi"));
+    assert!(rtf.contains("{\\colortbl;\\red255\\green255\\blue255;"));
+    assert!(rtf.contains("\\fs20\\pard\\cbpat1\\cb1\\chcbpat1\n"));
+    assert!(rtf.contains("{\\cf3 fn}"));
+    assert!(rtf.contains("\\u233?"));
+    assert_eq!(rtf.matches("\\line\n").count(), 2);
+    assert!(rtf.ends_with("}\n"));
+
+    let svg = SvgRenderer::default()
+        .with_theme(ColorTheme::GITHUB_LIGHT)
+        .with_numlines(true)
+        .render("fn a<'b>()\n");
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.contains("<rect width=\"100%\" height=\"100%\" fill=\"#ffffff\"/>"));
+    assert!(svg.contains(">1</text>"));
+    assert!(svg.contains("<tspan fill=\"#cf222e\">fn</tspan>"));
+    assert!(svg.contains("&lt;"));
+    assert!(svg.ends_with("</svg>\n"));
+}
+
+#[test]
+fn rich_copy() {
+    let copy = RichCopy::new(&ColorTheme::GITHUB_LIGHT, &Syntax::rust(), "let x = 1;");
+    assert_eq!(copy.text, "let x = 1;");
+    assert_eq!(
+        copy.html,
+        HtmlRenderer::default()
+            .with_theme(ColorTheme::GITHUB_LIGHT)
+            .render("let x = 1;")
+    );
+    assert!(
+        copy.html
+            .contains("<span style=\"color: #cf222e;\">let</span>")
+    );
+    assert!(copy.rtf.starts_with("{\\rtf1"));
+    assert!(copy.rtf.contains("let}"));
+}
+
+#[cfg(feature = "ratatui")]
+#[test]
+fn ratatui_code_view() {
+    use ratatui::buffer::Buffer;
+    use ratatui::layout::Rect;
+    use ratatui::style::Color;
+    use ratatui::widgets::Widget;
+
+    let theme = ColorTheme::GITHUB_LIGHT;
+    let chrome = theme.chrome_colors();
+    let view = CodeView::new("fn a\nlet b\nc\n")
+        .with_theme(theme.clone())
+        .with_current_line(Some(1));
+    let lines = view.lines();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[1].to_string(), "2 let b");
+    assert_eq!(lines[1].style.bg, Some(chrome.current_line.into()));
+    assert_eq!(
+        lines[0].spans[1].style.fg,
+        Some(Color::Rgb(0xcf, 0x22, 0x2e))
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+    view.with_scroll(1)
+        .with_numlines(false)
+        .render(buf.area, &mut buf);
+    assert_eq!(buf[(0, 0)].symbol(), "l");
+    assert_eq!(buf[(0, 1)].symbol(), "c");
+    assert_eq!(buf[(7, 1)].bg, theme.bg.into());
+}
+
+#[test]
+fn syntax_registry() {
+    assert_eq!(Syntax::builtin().len(), 49);
+    for syntax in Syntax::builtin() {
+        assert_eq!(Syntax::by_name(syntax.language).as_ref(), Some(&syntax));
+    }
+    assert_eq!(Syntax::by_name("c++").map(|s| s.language), Some("C++"));
+    assert_eq!(
+        Syntax::by_extension("TSX").map(|s| s.language),
+        Some("TypeScript")
+    );
+    assert_eq!(
+        Syntax::for_path("src/main.rs").map(|s| s.language),
+        Some("Rust")
+    );
+    assert_eq!(
+        Syntax::for_path("ci/Dockerfile").map(|s| s.language),
+        Some("Dockerfile")
+    );
+    assert_eq!(
+        Syntax::for_path("CMakeLists.txt").map(|s| s.language),
+        Some("CMake")
+    );
+    assert!(Syntax::for_path("notes.txt").is_none());
+    // Plain text has no comments at all
+    let plain = Token::default().tokens(&Syntax::simple(""), "hello # world");
+    assert!(
+        plain
+            .iter()
+            .all(|t| !matches!(t.ty(), TokenType::Comment(_)))
+    );
+    let ansi = AnsiRenderer::default()
+        .with_depth(ColorDepth::Plain)
+        .with_numlines(true)
+        .with_line_range(2..=3)
+        .render("/*\na\n*/\nb\n");
+    assert_eq!(ansi, "2 a\n3 */\n");
+    let html = HtmlRenderer::default()
+        .with_line_range(3..=3)
+        .render("/*\na\n*/\n");
+    assert!(
+        html.contains(
+            "<code><span style=\"color: #928374; font-style: italic;\">*/</span>\n</code>"
+        )
+    );
+}
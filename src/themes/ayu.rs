@@ -1,57 +1,128 @@
-use super::ColorTheme;
+use super::{ChromeColors, ColorTheme, FontStyle, HexColor, ThemeStyles};
+use std::borrow::Cow;
+
+// Font styles of ayu scopes.
+const AYU_STYLES: ThemeStyles = ThemeStyles {
+    comments: FontStyle::ITALIC,      // comment
+    special: FontStyle::ITALIC,       // variable.language
+    headings: FontStyle::BOLD,        // markup.heading
+    emphasis: FontStyle::ITALIC,      // markup.italic
+    hyperlinks: FontStyle::UNDERLINE, // markup.underline.link
+    ..ThemeStyles::NONE
+};
 
 impl ColorTheme {
     /// Author: André Sá <enkodr@outlook.com>
     ///
     /// Based on the AYU theme colors from <https://github.com/dempfi/ayu>
     pub const AYU: ColorTheme = ColorTheme {
-        name: "Ayu",
+        name: Cow::Borrowed("Ayu"),
         dark: false,
-        bg: "#fafafa",
-        cursor: "#5c6166",      // foreground
-        selection: "#fa8d3e",   // orange
-        comments: "#828c9a",    // gray
-        functions: "#ffaa33",   // yellow
-        keywords: "#fa8d3e",    // orange
-        literals: "#5c6166",    // foreground
-        numerics: "#a37acc",    // magenta
-        punctuation: "#5c6166", // foreground
-        strs: "#86b300",        // green
-        types: "#399ee6",       // blue
-        special: "#f07171",     // red
+        bg: HexColor::hex("#fafafa"),
+        cursor: HexColor::hex("#5c6166"),      // foreground
+        selection: HexColor::hex("#fa8d3e"),   // orange
+        comments: HexColor::hex("#828c9a"),    // gray
+        functions: HexColor::hex("#ffaa33"),   // yellow
+        keywords: HexColor::hex("#fa8d3e"),    // orange
+        literals: HexColor::hex("#5c6166"),    // foreground
+        numerics: HexColor::hex("#a37acc"),    // magenta
+        punctuation: HexColor::hex("#5c6166"), // foreground
+        strs: HexColor::hex("#86b300"),        // green
+        types: HexColor::hex("#399ee6"),       // blue
+        special: HexColor::hex("#f07171"),     // red
+        tags: HexColor::hex("#55b4d4"),        // cyan
+        attributes: HexColor::hex("#ffaa33"),  // yellow
+        headings: HexColor::hex("#fa8d3e"),    // orange
+        emphasis: HexColor::hex("#a37acc"),    // magenta
+        styles: AYU_STYLES,
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#fafafa"),             // background
+            line_numbers: HexColor::hex("#8a9199"),       // gutter.normal
+            active_line_number: HexColor::hex("#5c6166"), // foreground
+            current_line: HexColor::hex("#f0f2f4"),       // line
+            matching_bracket: HexColor::hex("#e7eaed"),   // gutter.active
+            whitespace: HexColor::hex("#adaeb1"),         // guide.normal
+            indent_guides: HexColor::hex("#e7eaed"),      // guide.active
+            search_match: HexColor::hex("#ffc94a80"),     // find match
+            error: HexColor::hex("#e65050"),              // error
+            warning: HexColor::hex("#f2ae49"),            // keyword
+            info: HexColor::hex("#399ee6"),               // entity
+            popup: HexColor::hex("#f3f4f5"),              // panel
+            popup_selection: HexColor::hex("#ffaa33"),    // accent
+        },
     };
 
     pub const AYU_MIRAGE: ColorTheme = ColorTheme {
-        name: "Ayu Mirage",
+        name: Cow::Borrowed("Ayu Mirage"),
         dark: true,
-        bg: "#1f2430",
-        cursor: "#cccac2",      // foreground
-        selection: "#ffad66",   // orange
-        comments: "#565b66",    // gray
-        functions: "#ffcc77",   // yellow
-        keywords: "#ffad66",    // orange
-        literals: "#cccac2",    // foreground
-        numerics: "#dfbfff",    // magenta
-        punctuation: "#cccac2", // foreground
-        strs: "#d5ff80",        // green
-        types: "#73d0ff",       // blue
-        special: "#f28779",     // red
+        bg: HexColor::hex("#1f2430"),
+        cursor: HexColor::hex("#cccac2"),      // foreground
+        selection: HexColor::hex("#ffad66"),   // orange
+        comments: HexColor::hex("#565b66"),    // gray
+        functions: HexColor::hex("#ffcc77"),   // yellow
+        keywords: HexColor::hex("#ffad66"),    // orange
+        literals: HexColor::hex("#cccac2"),    // foreground
+        numerics: HexColor::hex("#dfbfff"),    // magenta
+        punctuation: HexColor::hex("#cccac2"), // foreground
+        strs: HexColor::hex("#d5ff80"),        // green
+        types: HexColor::hex("#73d0ff"),       // blue
+        special: HexColor::hex("#f28779"),     // red
+        tags: HexColor::hex("#5ccfe6"),        // cyan
+        attributes: HexColor::hex("#ffcc77"),  // yellow
+        headings: HexColor::hex("#ffad66"),    // orange
+        emphasis: HexColor::hex("#dfbfff"),    // magenta
+        styles: AYU_STYLES,
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#1f2430"),             // background
+            line_numbers: HexColor::hex("#707a8c"),       // gutter.normal
+            active_line_number: HexColor::hex("#cccac2"), // foreground
+            current_line: HexColor::hex("#1a1f29"),       // line
+            matching_bracket: HexColor::hex("#2d3543"),   // gutter.active
+            whitespace: HexColor::hex("#3d4149"),         // guide.normal
+            indent_guides: HexColor::hex("#2d3543"),      // guide.active
+            search_match: HexColor::hex("#69538080"),     // find match
+            error: HexColor::hex("#ff6666"),              // error
+            warning: HexColor::hex("#ffad66"),            // keyword
+            info: HexColor::hex("#73d0ff"),               // entity
+            popup: HexColor::hex("#1c212b"),              // panel
+            popup_selection: HexColor::hex("#ffcc66"),    // accent
+        },
     };
 
     pub const AYU_DARK: ColorTheme = ColorTheme {
-        name: "Ayu Dark",
+        name: Cow::Borrowed("Ayu Dark"),
         dark: true,
-        bg: "#0f1419",
-        cursor: "#bfbdb6",      // foreground
-        selection: "#ffad66",   // orange
-        comments: "#5c6773",    // gray
-        functions: "#e6b450",   // yellow
-        keywords: "#ffad66",    // orange
-        literals: "#bfbdb6",    // foreground
-        numerics: "#dfbfff",    // magenta
-        punctuation: "#bfbdb6", // foreground
-        strs: "#aad94c",        // green
-        types: "#59c2ff",       // blue
-        special: "#f28779",     // red
+        bg: HexColor::hex("#0f1419"),
+        cursor: HexColor::hex("#bfbdb6"),      // foreground
+        selection: HexColor::hex("#ffad66"),   // orange
+        comments: HexColor::hex("#5c6773"),    // gray
+        functions: HexColor::hex("#e6b450"),   // yellow
+        keywords: HexColor::hex("#ffad66"),    // orange
+        literals: HexColor::hex("#bfbdb6"),    // foreground
+        numerics: HexColor::hex("#dfbfff"),    // magenta
+        punctuation: HexColor::hex("#bfbdb6"), // foreground
+        strs: HexColor::hex("#aad94c"),        // green
+        types: HexColor::hex("#59c2ff"),       // blue
+        special: HexColor::hex("#f28779"),     // red
+        tags: HexColor::hex("#39bae6"),        // cyan
+        attributes: HexColor::hex("#e6b450"),  // yellow
+        headings: HexColor::hex("#ffad66"),    // orange
+        emphasis: HexColor::hex("#dfbfff"),    // magenta
+        styles: AYU_STYLES,
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#0f1419"),             // background
+            line_numbers: HexColor::hex("#565b66"),       // gutter.normal
+            active_line_number: HexColor::hex("#bfbdb6"), // foreground
+            current_line: HexColor::hex("#131721"),       // line
+            matching_bracket: HexColor::hex("#273747"),   // gutter.active
+            whitespace: HexColor::hex("#2d3640"),         // guide.normal
+            indent_guides: HexColor::hex("#1f2430"),      // guide.active
+            search_match: HexColor::hex("#3e4b5e80"),     // find match
+            error: HexColor::hex("#d95757"),              // error
+            warning: HexColor::hex("#ff8f40"),            // keyword
+            info: HexColor::hex("#59c2ff"),               // entity
+            popup: HexColor::hex("#0d1017"),              // panel
+            popup_selection: HexColor::hex("#e6b450"),    // accent
+        },
     };
 }
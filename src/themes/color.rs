@@ -0,0 +1,468 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Default, Hash, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// RGBA color, written in hexadecimal notation as used in HTML and CSS.
+pub struct HexColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl HexColor {
+    /// Fully transparent, written as `none`.
+    pub const NONE: HexColor = HexColor::rgba(0, 0, 0, 0);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        HexColor { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        HexColor { r, g, b, a }
+    }
+
+    /// Parses `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa` or `none` at compile time.
+    ///
+    /// # Panics
+    /// If the color is malformed.
+    pub const fn hex(hex: &str) -> Self {
+        match HexColor::parse(hex) {
+            Some(color) => color,
+            None => panic!("malformed hex color"),
+        }
+    }
+
+    /// Parses `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa` or `none`.
+    /// See `FromStr` for CSS functions and named colors.
+    pub const fn parse(hex: &str) -> Option<Self> {
+        let bytes = hex.as_bytes();
+        if let [b'n', b'o', b'n', b'e'] = bytes {
+            return Some(HexColor::NONE);
+        }
+        let short = matches!(bytes.len(), 4 | 5);
+        if !(short || matches!(bytes.len(), 7 | 9)) || bytes[0] != b'#' {
+            return None;
+        }
+        let step = if short { 1 } else { 2 };
+        let mut channels = [255; 4];
+        let mut i = 0;
+        while 1 + i * step < bytes.len() {
+            let at = 1 + i * step;
+            let channel = if short {
+                nibble(bytes[at])
+            } else {
+                byte(bytes, at)
+            };
+            channels[i] = match channel {
+                Some(c) if short => c * 17,
+                Some(c) => c,
+                None => return None,
+            };
+            i += 1;
+        }
+        let [r, g, b, a] = channels;
+        Some(HexColor::rgba(r, g, b, a))
+    }
+
+    pub const fn is_none(&self) -> bool {
+        self.a == 0
+    }
+
+    /// WCAG relative luminance, from 0 for black to 1 for white. Alpha is ignored.
+    pub fn luminance(&self) -> f32 {
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// WCAG contrast ratio, from 1 for equal colors to 21 for black on white.
+    pub fn contrast(&self, other: HexColor) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Moves `t` of the way towards `other`, keeping own alpha.
+    pub fn mix(&self, other: HexColor, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| channel(a as f32 + (b as f32 - a as f32) * t);
+        HexColor::rgba(
+            mix(self.r, other.r),
+            mix(self.g, other.g),
+            mix(self.b, other.b),
+            self.a,
+        )
+    }
+
+    /// Inverts perceptual lightness in Oklab, keeping hue and chroma. `none` stays `none`.
+    pub fn invert_lightness(&self) -> Self {
+        if self.is_none() {
+            return *self;
+        }
+        let [l, a, b] = self.oklab();
+        HexColor::from_oklab([1.0 - l, a, b], self.a)
+    }
+
+    /// Color from Oklch lightness `0..=1`, chroma and hue in degrees, clipped to sRGB.
+    pub fn oklch(lightness: f32, chroma: f32, hue: f32) -> Self {
+        let (sin, cos) = hue.to_radians().sin_cos();
+        HexColor::from_oklab([lightness, chroma * cos, chroma * sin], 255)
+    }
+
+    /// Oklch hue in degrees.
+    pub fn hue(&self) -> f32 {
+        let [_, a, b] = self.oklab();
+        b.atan2(a).to_degrees()
+    }
+
+    fn oklab(&self) -> [f32; 3] {
+        let [r, g, b] = [self.r, self.g, self.b].map(linear);
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        [
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        ]
+    }
+
+    fn from_oklab([lightness, a, b]: [f32; 3], alpha: u8) -> Self {
+        let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m = (lightness - 0.105_561_35 * a - 0.063_854_17 * b).powi(3);
+        let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+        let [r, g, b] = [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+        .map(gamma);
+        HexColor::rgba(r, g, b, alpha)
+    }
+}
+
+// sRGB channel to linear light.
+fn linear(channel: u8) -> f32 {
+    let c = channel as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Linear light to sRGB channel.
+fn gamma(c: f32) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    channel(c * 255.0)
+}
+
+const fn byte(bytes: &[u8], i: usize) -> Option<u8> {
+    match (nibble(bytes[i]), nibble(bytes[i + 1])) {
+        (Some(high), Some(low)) => Some(high << 4 | low),
+        _ => None,
+    }
+}
+
+const fn nibble(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+impl fmt::Display for HexColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let HexColor { r, g, b, a } = *self;
+        match a {
+            0 => write!(f, "none"),
+            255 => write!(f, "#{r:02x}{g:02x}{b:02x}"),
+            _ => write!(f, "#{r:02x}{g:02x}{b:02x}{a:02x}"),
+        }
+    }
+}
+
+/// Parses hexadecimal notation, `rgb()`, `rgba()`, `hsl()`, `hsla()` and CSS named colors.
+impl FromStr for HexColor {
+    type Err = String;
+
+    fn from_str(color: &str) -> Result<Self, Self::Err> {
+        let css = color.trim().to_ascii_lowercase();
+        let parsed = HexColor::parse(&css)
+            .or_else(|| function(&css, "rgb").and_then(rgb))
+            .or_else(|| function(&css, "hsl").and_then(hsl))
+            .or_else(|| named(&css));
+        parsed.ok_or_else(|| format!("malformed color `{color}`"))
+    }
+}
+
+// Arguments of `name(...)` or `namea(...)`, separated by commas, spaces or `/`.
+fn function<'a>(css: &'a str, name: &str) -> Option<Vec<&'a str>> {
+    let rest = css.strip_prefix(name)?;
+    let rest = rest.strip_prefix('a').unwrap_or(rest).trim_start();
+    let args = rest.strip_prefix('(')?.strip_suffix(')')?;
+    let args = args
+        .split([',', ' ', '/'])
+        .filter(|arg| !arg.is_empty())
+        .collect::<Vec<_>>();
+    matches!(args.len(), 3 | 4).then_some(args)
+}
+
+// Number or percentage of `max`.
+fn number(arg: &str, max: f32) -> Option<f32> {
+    match arg.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok().map(|p| p / 100.0 * max),
+        None => arg.parse::<f32>().ok(),
+    }
+    .filter(|n| n.is_finite())
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn channel(value: f32) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+fn alpha(args: &[&str]) -> Option<u8> {
+    match args.get(3) {
+        Some(arg) => number(arg, 1.0).map(|a| channel(a * 255.0)),
+        None => Some(255),
+    }
+}
+
+fn rgb(args: Vec<&str>) -> Option<HexColor> {
+    let r = number(args[0], 255.0)?;
+    let g = number(args[1], 255.0)?;
+    let b = number(args[2], 255.0)?;
+    Some(HexColor::rgba(
+        channel(r),
+        channel(g),
+        channel(b),
+        alpha(&args)?,
+    ))
+}
+
+fn hsl(args: Vec<&str>) -> Option<HexColor> {
+    let h = number(args[0].trim_end_matches("deg"), 360.0)?.rem_euclid(360.0) / 60.0;
+    let s = number(args[1], 1.0)?.clamp(0.0, 1.0);
+    let l = number(args[2], 1.0)?.clamp(0.0, 1.0);
+    let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h {
+        h if h < 1.0 => (chroma, x, 0.0),
+        h if h < 2.0 => (x, chroma, 0.0),
+        h if h < 3.0 => (0.0, chroma, x),
+        h if h < 4.0 => (0.0, x, chroma),
+        h if h < 5.0 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = l - chroma / 2.0;
+    let [r, g, b] = [r, g, b].map(|c| channel((c + m) * 255.0));
+    Some(HexColor::rgba(r, g, b, alpha(&args)?))
+}
+
+fn named(css: &str) -> Option<HexColor> {
+    if css == "transparent" {
+        return Some(HexColor::NONE);
+    }
+    let i = NAMED.binary_search_by_key(&css, |(name, _)| name).ok()?;
+    let [_, r, g, b] = NAMED[i].1.to_be_bytes();
+    Some(HexColor::rgb(r, g, b))
+}
+
+#[cfg(feature = "ratatui")]
+impl From<HexColor> for ratatui::style::Color {
+    fn from(color: HexColor) -> Self {
+        if color.is_none() {
+            ratatui::style::Color::Reset
+        } else {
+            ratatui::style::Color::Rgb(color.r, color.g, color.b)
+        }
+    }
+}
+
+#[cfg(feature = "egui")]
+impl From<HexColor> for egui::Color32 {
+    fn from(color: HexColor) -> Self {
+        egui::Color32::from_rgba_unmultiplied(color.r, color.g, color.b, color.a)
+    }
+}
+
+#[cfg(feature = "egui")]
+impl From<egui::Color32> for HexColor {
+    fn from(color: egui::Color32) -> Self {
+        let [r, g, b, a] = color.to_srgba_unmultiplied();
+        HexColor::rgba(r, g, b, a)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HexColor {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HexColor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        hex.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// CSS named colors, sorted for binary search.
+const NAMED: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
@@ -0,0 +1,232 @@
+use super::{ColorTheme, FontStyle, HexColor, is_editor_field};
+use crate::{CodeEditor, Syntax};
+use egui::color_picker::{Alpha, color_edit_button_srgba};
+use egui::{Color32, TextEdit};
+use std::collections::BTreeSet;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Format of the source shown by `ThemeEditor`.
+pub enum ThemeExport {
+    #[default]
+    Rust,
+    Toml,
+    Json,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// Widget editing every color and font style of a `ColorTheme` next to a live `CodeEditor` preview.
+pub struct ThemeEditor {
+    id: String,
+    theme: ColorTheme,
+    syntax: Syntax,
+    sample: String,
+    fontsize: f32,
+    export: ThemeExport,
+}
+
+impl Default for ThemeEditor {
+    fn default() -> Self {
+        ThemeEditor::new(ColorTheme::default())
+    }
+}
+
+impl ThemeEditor {
+    pub fn new(theme: ColorTheme) -> Self {
+        let syntax = Syntax::rust();
+        ThemeEditor {
+            id: String::from("Theme Editor"),
+            theme,
+            sample: sample(&syntax),
+            syntax,
+            fontsize: 10.0,
+            export: ThemeExport::default(),
+        }
+    }
+
+    pub fn id_source(self, id_source: impl Into<String>) -> Self {
+        ThemeEditor {
+            id: id_source.into(),
+            ..self
+        }
+    }
+
+    /// Previews the syntax with a sample made of its own keywords, types and comments.
+    pub fn with_syntax(self, syntax: Syntax) -> Self {
+        ThemeEditor {
+            sample: sample(&syntax),
+            syntax,
+            ..self
+        }
+    }
+
+    pub fn with_sample(self, sample: impl Into<String>) -> Self {
+        ThemeEditor {
+            sample: sample.into(),
+            ..self
+        }
+    }
+
+    pub fn with_fontsize(self, fontsize: f32) -> Self {
+        ThemeEditor { fontsize, ..self }
+    }
+
+    pub fn with_export(self, export: ThemeExport) -> Self {
+        ThemeEditor { export, ..self }
+    }
+
+    pub fn theme(&self) -> &ColorTheme {
+        &self.theme
+    }
+
+    pub fn into_theme(self) -> ColorTheme {
+        self.theme
+    }
+
+    /// Exported source of the edited theme.
+    pub fn export(&self) -> String {
+        match self.export {
+            ThemeExport::Rust => self.theme.to_rust(&const_name(&self.theme.name)),
+            ThemeExport::Toml => self.theme.to_toml(),
+            ThemeExport::Json => self.theme.to_json(),
+        }
+    }
+
+    /// Show Theme Editor, returns `true` if the theme has changed.
+    pub fn show(&mut self, ui: &mut egui::Ui) -> bool {
+        let before = self.theme.clone();
+        ui.columns(2, |columns| {
+            egui::ScrollArea::vertical()
+                .id_salt(format!("{}_fields", self.id))
+                .show(&mut columns[0], |ui| self.fields_show(ui));
+            let ui = &mut columns[1];
+            CodeEditor::default()
+                .id_source(format!("{}_preview", self.id))
+                .with_theme(self.theme.clone())
+                .with_syntax(self.syntax.clone())
+                .with_fontsize(self.fontsize)
+                .with_rows(12)
+                .show(ui, &mut self.sample);
+            ui.horizontal(|h| {
+                h.selectable_value(&mut self.export, ThemeExport::Rust, "Rust");
+                h.selectable_value(&mut self.export, ThemeExport::Toml, "TOML");
+                h.selectable_value(&mut self.export, ThemeExport::Json, "JSON");
+                if h.button("Copy").clicked() {
+                    h.ctx().copy_text(self.export());
+                }
+            });
+            let mut export = self.export();
+            egui::ScrollArea::vertical()
+                .id_salt(format!("{}_export", self.id))
+                .show(ui, |ui| {
+                    ui.add(
+                        TextEdit::multiline(&mut export)
+                            .code_editor()
+                            .desired_width(f32::INFINITY)
+                            .interactive(false),
+                    );
+                });
+        });
+        self.theme != before
+    }
+
+    fn fields_show(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|h| {
+            h.label("name");
+            let mut name = self.theme.name.to_string();
+            if h.text_edit_singleline(&mut name).changed() {
+                self.theme.name = name.into();
+            }
+            h.checkbox(&mut self.theme.dark, "dark");
+        });
+        let selection = self.theme.selection;
+        let mut styles = self.theme.styles;
+        let mut slots = styles.all_mut().into_iter();
+        egui::Grid::new(format!("{}_grid", self.id))
+            .striped(true)
+            .show(ui, |grid| {
+                for (field, color) in self.theme.fields() {
+                    grid.label(field);
+                    let mut picked = Color32::from(*color);
+                    if color_edit_button_srgba(grid, &mut picked, Alpha::OnlyBlend).changed() {
+                        *color = picked.into();
+                    }
+                    grid.horizontal(|h| {
+                        if field.starts_with("chrome.") {
+                            let unset = h.add_enabled(!color.is_none(), egui::Button::new("none"));
+                            if unset.on_hover_text("Use fallback color").clicked() {
+                                *color = HexColor::NONE;
+                            }
+                        }
+                        if is_editor_field(field) {
+                            return;
+                        }
+                        if let Some((_, style)) = slots.next() {
+                            style_edit(h, style, selection);
+                        }
+                    });
+                    grid.end_row();
+                }
+                // Styles without a color of their own
+                for (field, style) in slots.by_ref() {
+                    grid.label(field);
+                    grid.label("");
+                    grid.horizontal(|h| style_edit(h, style, selection));
+                    grid.end_row();
+                }
+            });
+        drop(slots);
+        self.theme.styles = styles;
+    }
+}
+
+fn style_edit(ui: &mut egui::Ui, style: &mut FontStyle, selection: HexColor) {
+    ui.toggle_value(&mut style.bold, "B");
+    ui.toggle_value(&mut style.italic, "I");
+    ui.toggle_value(&mut style.underline, "U");
+    ui.toggle_value(&mut style.strikethrough, "S");
+    let mut has_background = style.background.is_some();
+    if ui.toggle_value(&mut has_background, "bg").changed() {
+        style.background = has_background.then_some(selection);
+    }
+    if let Some(background) = &mut style.background {
+        let mut picked = Color32::from(*background);
+        if color_edit_button_srgba(ui, &mut picked, Alpha::OnlyBlend).changed() {
+            *background = picked.into();
+        }
+    }
+}
+
+// Comment, keywords, types, literals and special words of the syntax.
+fn sample(syntax: &Syntax) -> String {
+    let first =
+        |words: &BTreeSet<&'static str>, fallback| words.first().copied().unwrap_or(fallback);
+    let last = |words: &BTreeSet<&'static str>, fallback| words.last().copied().unwrap_or(fallback);
+    let comment = if syntax.comment.is_empty() {
+        let [open, close] = syntax.comment_multiline;
+        format!("{open} {} preview {close}", syntax.language)
+    } else {
+        format!("{} {} preview", syntax.comment, syntax.language)
+    };
+    format!(
+        "{comment}\n{} name({} value) {{\n    {} \"string\" + 42 * 3.14;\n    {}\n}}\n",
+        first(&syntax.keywords, "fn"),
+        first(&syntax.types, "int"),
+        last(&syntax.keywords, "return"),
+        first(&syntax.special, "true"),
+    )
+}
+
+// Constant name from theme name, as in `AYU_MIRAGE`.
+fn const_name(name: &str) -> String {
+    let name = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_uppercase)
+        .collect::<Vec<_>>()
+        .join("_");
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("THEME_{name}")
+    }
+}
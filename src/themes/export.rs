@@ -0,0 +1,301 @@
+use super::{ColorTheme, FontStyle, HexColor, ThemeStyles};
+use crate::TokenType;
+use std::fmt::Write;
+
+// Fields of `ThemeStyles` and `ChromeColors`.
+const STYLES: usize = 14;
+const CHROME: usize = 13;
+
+// One token type for every CSS class.
+const CSS_TYPES: [TokenType; 23] = [
+    TokenType::Attribute,
+    TokenType::Column(0),
+    TokenType::Column(1),
+    TokenType::Column(2),
+    TokenType::Column(3),
+    TokenType::Column(4),
+    TokenType::Column(5),
+    TokenType::Comment(false),
+    TokenType::Emphasis,
+    TokenType::Function,
+    TokenType::Heading,
+    TokenType::Key,
+    TokenType::Keyword,
+    TokenType::Literal,
+    TokenType::Hyperlink,
+    TokenType::Numeric(false),
+    TokenType::Punctuation(' '),
+    TokenType::Special,
+    TokenType::Str('"'),
+    TokenType::Tag,
+    TokenType::Type,
+    TokenType::Whitespace(' '),
+    TokenType::Unknown,
+];
+
+impl ColorTheme {
+    /// Rust source of a constant in the same shape as the built-in themes.
+    pub fn to_rust(&self, const_name: &str) -> String {
+        let mut source = format!("pub const {const_name}: ColorTheme = ColorTheme {{\n");
+        let _ = writeln!(source, "    name: Cow::Borrowed({:?}),", self.name);
+        let _ = writeln!(source, "    dark: {},", self.dark);
+        let (styles, chrome) = (self.styled_slots(), self.set_chrome());
+        for (field, color) in self.colors() {
+            if !field.starts_with("chrome.") {
+                let _ = writeln!(source, "    {field}: HexColor::hex(\"{color}\"),");
+            }
+        }
+        if styles.is_empty() {
+            source.push_str("    styles: ThemeStyles::NONE,\n");
+        } else if self.styles == ThemeStyles::CLASSIC {
+            source.push_str("    styles: ThemeStyles::CLASSIC,\n");
+        } else {
+            source.push_str("    styles: ThemeStyles {\n");
+            let all = styles.len() == STYLES;
+            for (field, style) in styles {
+                let _ = writeln!(source, "        {field}: {},", rust_style(style));
+            }
+            if !all {
+                source.push_str("        ..ThemeStyles::NONE\n");
+            }
+            source.push_str("    },\n");
+        }
+        if chrome.is_empty() {
+            source.push_str("    chrome: ChromeColors::NONE,\n");
+        } else {
+            source.push_str("    chrome: ChromeColors {\n");
+            let all = chrome.len() == CHROME;
+            for (field, color) in chrome {
+                let _ = writeln!(source, "        {field}: HexColor::hex(\"{color}\"),");
+            }
+            if !all {
+                source.push_str("        ..ChromeColors::NONE\n");
+            }
+            source.push_str("    },\n");
+        }
+        source.push_str("};\n");
+        source
+    }
+
+    /// TOML as read by `serde`, plain styles and unset chrome colors are left out.
+    pub fn to_toml(&self) -> String {
+        let mut toml = format!("name = {}\ndark = {}\n", quote(&self.name), self.dark);
+        let (styles, chrome) = (self.styled_slots(), self.set_chrome());
+        for (field, color) in self.colors() {
+            if !field.starts_with("chrome.") {
+                let _ = writeln!(toml, "{field} = \"{color}\"");
+            }
+        }
+        for (field, style) in styles {
+            let _ = writeln!(toml, "\n[styles.{field}]");
+            for (flag, on) in flags(style) {
+                if on {
+                    let _ = writeln!(toml, "{flag} = true");
+                }
+            }
+            if let Some(background) = style.background {
+                let _ = writeln!(toml, "background = \"{background}\"");
+            }
+        }
+        if !chrome.is_empty() {
+            toml.push_str("\n[chrome]\n");
+            for (field, color) in chrome {
+                let _ = writeln!(toml, "{field} = \"{color}\"");
+            }
+        }
+        toml
+    }
+
+    /// JSON as read by `serde`, plain styles and unset chrome colors are left out.
+    pub fn to_json(&self) -> String {
+        let mut entries = vec![
+            format!("\"name\": {}", quote(&self.name)),
+            format!("\"dark\": {}", self.dark),
+        ];
+        let (styles, chrome) = (self.styled_slots(), self.set_chrome());
+        for (field, color) in self.colors() {
+            if !field.starts_with("chrome.") {
+                entries.push(format!("\"{field}\": \"{color}\""));
+            }
+        }
+        let styles = styles
+            .into_iter()
+            .map(|(field, style)| {
+                let mut attributes = flags(style)
+                    .into_iter()
+                    .filter(|(_, on)| *on)
+                    .map(|(flag, _)| format!("\"{flag}\": true"))
+                    .collect::<Vec<_>>();
+                if let Some(background) = style.background {
+                    attributes.push(format!("\"background\": \"{background}\""));
+                }
+                format!("\"{field}\": {{ {} }}", attributes.join(", "))
+            })
+            .collect::<Vec<_>>();
+        entries.push(format!("\"styles\": {}", json_object(&styles, "  ")));
+        let chrome = chrome
+            .into_iter()
+            .map(|(field, color)| format!("\"{field}\": \"{color}\""))
+            .collect::<Vec<_>>();
+        entries.push(format!("\"chrome\": {}", json_object(&chrome, "  ")));
+        json_object(&entries, "") + "\n"
+    }
+
+    /// CSS stylesheet for code inside `selector`, with a class per token type as in `.tok-keyword`.
+    pub fn to_css(&self, selector: &str) -> String {
+        let mut css = format!(
+            "{selector} {{ color: {}; background-color: {}; }}\n",
+            css_color(self.literals),
+            css_color(self.bg)
+        );
+        let _ = writeln!(
+            css,
+            "{selector} ::selection {{ background-color: {}; }}",
+            css_color(self.selection)
+        );
+        let chrome = self.chrome_colors();
+        let _ = writeln!(
+            css,
+            "{selector} .tok-line-number {{ color: {}; user-select: none; }}",
+            css_color(chrome.line_numbers)
+        );
+        let _ = writeln!(
+            css,
+            "{selector} .tok-line-highlighted {{ display: inline-block; width: 100%; background-color: {}; }}",
+            css_color(chrome.current_line)
+        );
+        for ty in CSS_TYPES {
+            let color = css_color(self.type_hex_color(ty));
+            let _ = writeln!(
+                css,
+                "{selector} .{} {{ {} }}",
+                ty.class(),
+                css_declarations(&color, self.type_style(ty))
+            );
+        }
+        css
+    }
+
+    /// Stylesheet of this theme, switching to `other` when the reader prefers its color scheme.
+    pub fn to_css_with(&self, other: &ColorTheme, selector: &str) -> String {
+        let scheme = if other.dark { "dark" } else { "light" };
+        let other = other.to_css(selector).replace('\n', "\n  ");
+        format!(
+            "{}@media (prefers-color-scheme: {scheme}) {{\n  {}}}\n",
+            self.to_css(selector),
+            other.trim_end_matches(' ')
+        )
+    }
+
+    // Style slots which aren't plain.
+    fn styled_slots(&self) -> Vec<(&'static str, FontStyle)> {
+        let mut styles = self.styles;
+        styles
+            .all_mut()
+            .into_iter()
+            .filter(|(_, style)| !style.is_plain())
+            .map(|(field, style)| (field, *style))
+            .collect()
+    }
+
+    // Chrome fields which are set, without the `chrome.` prefix.
+    fn set_chrome(&self) -> Vec<(&'static str, HexColor)> {
+        self.colors()
+            .into_iter()
+            .filter_map(|(field, color)| Some((field.strip_prefix("chrome.")?, color)))
+            .filter(|(_, color)| !color.is_none())
+            .collect()
+    }
+}
+
+fn flags(style: FontStyle) -> [(&'static str, bool); 4] {
+    [
+        ("bold", style.bold),
+        ("italic", style.italic),
+        ("underline", style.underline),
+        ("strikethrough", style.strikethrough),
+    ]
+}
+
+fn rust_style(style: FontStyle) -> String {
+    if style == FontStyle::BOLD {
+        return String::from("FontStyle::BOLD");
+    }
+    if style == FontStyle::ITALIC {
+        return String::from("FontStyle::ITALIC");
+    }
+    if style == FontStyle::UNDERLINE {
+        return String::from("FontStyle::UNDERLINE");
+    }
+    let mut source = String::from("FontStyle::NONE");
+    for (flag, on) in flags(style) {
+        if on {
+            let _ = write!(source, ".{flag}()");
+        }
+    }
+    if let Some(background) = style.background {
+        let _ = write!(source, ".with_background(HexColor::hex(\"{background}\"))");
+    }
+    source
+}
+
+pub(crate) fn css_color(color: HexColor) -> String {
+    if color.is_none() {
+        String::from("transparent")
+    } else {
+        color.to_string()
+    }
+}
+
+// Declarations of a token with its CSS color, as in `#fa8d3e` or `transparent`.
+pub(crate) fn css_declarations(color: &str, style: FontStyle) -> String {
+    let mut css = format!("color: {color};");
+    if style.bold {
+        css.push_str(" font-weight: bold;");
+    }
+    if style.italic {
+        css.push_str(" font-style: italic;");
+    }
+    let lines = [
+        (style.underline, "underline"),
+        (style.strikethrough, "line-through"),
+    ]
+    .into_iter()
+    .filter_map(|(on, line)| on.then_some(line))
+    .collect::<Vec<_>>();
+    if !lines.is_empty() {
+        let _ = write!(css, " text-decoration: {};", lines.join(" "));
+    }
+    if let Some(background) = style.background {
+        let _ = write!(css, " background-color: {};", css_color(background));
+    }
+    css
+}
+
+// Basic string of TOML, which is also a JSON string.
+fn quote(text: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() && c <= '\u{7f}' => {
+                let _ = write!(quoted, "\\u{:04x}", u32::from(c));
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn json_object(entries: &[String], indent: &str) -> String {
+    if entries.is_empty() {
+        return String::from("{}");
+    }
+    let inner = format!(",\n{indent}  ");
+    format!("{{\n{indent}  {}\n{indent}}}", entries.join(&inner))
+}
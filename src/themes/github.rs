@@ -1,39 +1,90 @@
-use super::ColorTheme;
+use super::{ChromeColors, ColorTheme, FontStyle, HexColor, ThemeStyles};
+use std::borrow::Cow;
 
 impl ColorTheme {
     /// Author : OwOSwordsman <owoswordsman@gmail.com>
     /// An unofficial GitHub theme, generated using colors from: <https://primer.style/primitives/colors>
     pub const GITHUB_DARK: ColorTheme = ColorTheme {
-        name: "Github Dark",
+        name: Cow::Borrowed("Github Dark"),
         dark: true,
-        bg: "#0d1117",          // default
-        cursor: "#d29922",      // attention.fg
-        selection: "#0c2d6b",   // scale.blue.8
-        comments: "#8b949e",    // fg.muted
-        functions: "#d2a8ff",   // scale.purple.2
-        keywords: "#ff7b72",    // scale.red.3
-        literals: "#c9d1d9",    // fg.default
-        numerics: "#79c0ff",    // scale.blue.2
-        punctuation: "#c9d1d9", // fg.default
-        strs: "#a5d6ff",        // scale.blue.1
-        types: "#ffa657",       // scale.orange.2
-        special: "#a5d6ff",     // scale.blue.1
+        bg: HexColor::hex("#0d1117"),          // default
+        cursor: HexColor::hex("#d29922"),      // attention.fg
+        selection: HexColor::hex("#0c2d6b"),   // scale.blue.8
+        comments: HexColor::hex("#8b949e"),    // fg.muted
+        functions: HexColor::hex("#d2a8ff"),   // scale.purple.2
+        keywords: HexColor::hex("#ff7b72"),    // scale.red.3
+        literals: HexColor::hex("#c9d1d9"),    // fg.default
+        numerics: HexColor::hex("#79c0ff"),    // scale.blue.2
+        punctuation: HexColor::hex("#c9d1d9"), // fg.default
+        strs: HexColor::hex("#a5d6ff"),        // scale.blue.1
+        types: HexColor::hex("#ffa657"),       // scale.orange.2
+        special: HexColor::hex("#a5d6ff"),     // scale.blue.1
+        tags: HexColor::hex("#7ee787"),        // scale.green.1
+        attributes: HexColor::hex("#79c0ff"),  // scale.blue.2
+        headings: HexColor::hex("#1f6feb"),    // scale.blue.5
+        emphasis: HexColor::hex("#c9d1d9"),    // fg.default
+        styles: ThemeStyles {
+            headings: FontStyle::BOLD,
+            emphasis: FontStyle::ITALIC,
+            hyperlinks: FontStyle::UNDERLINE,
+            ..ThemeStyles::NONE
+        },
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#0d1117"),             // default
+            line_numbers: HexColor::hex("#6e7681"),       // fg.subtle
+            active_line_number: HexColor::hex("#c9d1d9"), // fg.default
+            current_line: HexColor::hex("#161b22"),       // canvas.subtle
+            matching_bracket: HexColor::hex("#3fb95040"), // success.fg
+            whitespace: HexColor::hex("#484f58"),         // scale.gray.6
+            indent_guides: HexColor::hex("#21262d"),      // border.muted
+            search_match: HexColor::hex("#9e6a0380"),     // scale.yellow.5
+            error: HexColor::hex("#f85149"),              // danger.fg
+            warning: HexColor::hex("#d29922"),            // attention.fg
+            info: HexColor::hex("#58a6ff"),               // accent.fg
+            popup: HexColor::hex("#161b22"),              // canvas.overlay
+            popup_selection: HexColor::hex("#1f6feb"),    // accent.emphasis
+        },
     };
 
     pub const GITHUB_LIGHT: ColorTheme = ColorTheme {
-        name: "Github Light",
+        name: Cow::Borrowed("Github Light"),
         dark: false,
-        bg: "#ffffff",          // default
-        cursor: "#000000",      // invert
-        selection: "#0550ae",   // scale.blue.6
-        comments: "#57606a",    // fg.muted
-        functions: "#8250df",   // done.fg
-        keywords: "#cf222e",    // scale.red.5
-        literals: "#24292f",    // fg.default
-        numerics: "#0550ae",    // scale.blue.6
-        punctuation: "#24292f", // fg.default
-        strs: "#0a3069",        // scale.blue.8
-        types: "#953800",       // scale.orange.6
-        special: "#a475f9",     // scale.purple.4
+        bg: HexColor::hex("#ffffff"),          // default
+        cursor: HexColor::hex("#000000"),      // invert
+        selection: HexColor::hex("#0550ae"),   // scale.blue.6
+        comments: HexColor::hex("#57606a"),    // fg.muted
+        functions: HexColor::hex("#8250df"),   // done.fg
+        keywords: HexColor::hex("#cf222e"),    // scale.red.5
+        literals: HexColor::hex("#24292f"),    // fg.default
+        numerics: HexColor::hex("#0550ae"),    // scale.blue.6
+        punctuation: HexColor::hex("#24292f"), // fg.default
+        strs: HexColor::hex("#0a3069"),        // scale.blue.8
+        types: HexColor::hex("#953800"),       // scale.orange.6
+        special: HexColor::hex("#a475f9"),     // scale.purple.4
+        tags: HexColor::hex("#116329"),        // scale.green.6
+        attributes: HexColor::hex("#0550ae"),  // scale.blue.6
+        headings: HexColor::hex("#0969da"),    // scale.blue.5
+        emphasis: HexColor::hex("#24292f"),    // fg.default
+        styles: ThemeStyles {
+            headings: FontStyle::BOLD,
+            emphasis: FontStyle::ITALIC,
+            hyperlinks: FontStyle::UNDERLINE,
+            ..ThemeStyles::NONE
+        },
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#ffffff"),             // default
+            line_numbers: HexColor::hex("#6e7781"),       // fg.subtle
+            active_line_number: HexColor::hex("#24292f"), // fg.default
+            current_line: HexColor::hex("#f6f8fa"),       // canvas.subtle
+            matching_bracket: HexColor::hex("#4ac26b40"), // success.fg
+            whitespace: HexColor::hex("#afb8c1"),         // scale.gray.3
+            indent_guides: HexColor::hex("#d0d7de"),      // border.default
+            search_match: HexColor::hex("#fae17d80"),     // scale.yellow.1
+            error: HexColor::hex("#cf222e"),              // danger.fg
+            warning: HexColor::hex("#9a6700"),            // attention.fg
+            info: HexColor::hex("#0969da"),               // accent.fg
+            popup: HexColor::hex("#ffffff"),              // canvas.overlay
+            popup_selection: HexColor::hex("#0969da"),    // accent.emphasis
+        },
     };
 }
@@ -1,41 +1,93 @@
-use super::ColorTheme;
+use super::{ChromeColors, ColorTheme, FontStyle, HexColor, ThemeStyles};
+use std::borrow::Cow;
+
+// Font styles of gruvbox highlight groups.
+const GRUVBOX_STYLES: ThemeStyles = ThemeStyles {
+    comments: FontStyle::ITALIC,      // Comment
+    functions: FontStyle::BOLD,       // Function
+    tags: FontStyle::BOLD,            // htmlTagName
+    headings: FontStyle::BOLD,        // Title
+    emphasis: FontStyle::ITALIC,      // markdownItalic
+    hyperlinks: FontStyle::UNDERLINE, // Underlined
+    ..ThemeStyles::NONE
+};
 
 impl ColorTheme {
     /// Author : Jakub Bartodziej <kubabartodziej@gmail.com>
     /// Theme uses the gruvbox dark palette with standard contrast <https://github.com/morhetz/gruvbox>
     pub const GRUVBOX: ColorTheme = ColorTheme {
-        name: "Gruvbox",
+        name: Cow::Borrowed("Gruvbox"),
         dark: true,
-        bg: "#282828",
-        cursor: "#a89984",      // fg4
-        selection: "#504945",   // bg2
-        comments: "#928374",    // gray1
-        functions: "#b8bb26",   // green1
-        keywords: "#fb4934",    // red1
-        literals: "#ebdbb2",    // fg1
-        numerics: "#d3869b",    // purple1
-        punctuation: "#fe8019", // orange1
-        strs: "#8ec07c",        // aqua1
-        types: "#fabd2f",       // yellow1
-        special: "#83a598",     // blue1
+        bg: HexColor::hex("#282828"),
+        cursor: HexColor::hex("#a89984"),      // fg4
+        selection: HexColor::hex("#504945"),   // bg2
+        comments: HexColor::hex("#928374"),    // gray1
+        functions: HexColor::hex("#b8bb26"),   // green1
+        keywords: HexColor::hex("#fb4934"),    // red1
+        literals: HexColor::hex("#ebdbb2"),    // fg1
+        numerics: HexColor::hex("#d3869b"),    // purple1
+        punctuation: HexColor::hex("#fe8019"), // orange1
+        strs: HexColor::hex("#8ec07c"),        // aqua1
+        types: HexColor::hex("#fabd2f"),       // yellow1
+        special: HexColor::hex("#83a598"),     // blue1
+        tags: HexColor::hex("#83a598"),        // blue1
+        attributes: HexColor::hex("#8ec07c"),  // aqua1
+        headings: HexColor::hex("#b8bb26"),    // green1
+        emphasis: HexColor::hex("#ebdbb2"),    // fg1
+        styles: GRUVBOX_STYLES,
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#282828"),             // bg0
+            line_numbers: HexColor::hex("#7c6f64"),       // bg4
+            active_line_number: HexColor::hex("#fabd2f"), // yellow1
+            current_line: HexColor::hex("#3c3836"),       // bg1
+            matching_bracket: HexColor::hex("#665c54"),   // bg3
+            whitespace: HexColor::hex("#504945"),         // bg2
+            indent_guides: HexColor::hex("#3c3836"),      // bg1
+            search_match: HexColor::hex("#fabd2f40"),     // yellow1
+            error: HexColor::hex("#fb4934"),              // red1
+            warning: HexColor::hex("#fabd2f"),            // yellow1
+            info: HexColor::hex("#83a598"),               // blue1
+            popup: HexColor::hex("#504945"),              // bg2
+            popup_selection: HexColor::hex("#83a598"),    // blue1
+        },
     };
 
     pub const GRUVBOX_DARK: ColorTheme = ColorTheme::GRUVBOX;
 
     pub const GRUVBOX_LIGHT: ColorTheme = ColorTheme {
-        name: "Gruvbox Light",
+        name: Cow::Borrowed("Gruvbox Light"),
         dark: false,
-        bg: "#fbf1c7",
-        cursor: "#7c6f64",      // fg4
-        selection: "#b57614",   // yellow1
-        comments: "#7c6f64",    // gray1
-        functions: "#79740e",   // green1
-        keywords: "#9d0006",    // red1
-        literals: "#282828",    // fg1
-        numerics: "#8f3f71",    // purple1
-        punctuation: "#af3a03", // orange1
-        strs: "#427b58",        // aqua1
-        types: "#b57614",       // yellow1
-        special: "#af3a03",     // orange1
+        bg: HexColor::hex("#fbf1c7"),
+        cursor: HexColor::hex("#7c6f64"),      // fg4
+        selection: HexColor::hex("#b57614"),   // yellow1
+        comments: HexColor::hex("#7c6f64"),    // gray1
+        functions: HexColor::hex("#79740e"),   // green1
+        keywords: HexColor::hex("#9d0006"),    // red1
+        literals: HexColor::hex("#282828"),    // fg1
+        numerics: HexColor::hex("#8f3f71"),    // purple1
+        punctuation: HexColor::hex("#af3a03"), // orange1
+        strs: HexColor::hex("#427b58"),        // aqua1
+        types: HexColor::hex("#b57614"),       // yellow1
+        special: HexColor::hex("#af3a03"),     // orange1
+        tags: HexColor::hex("#076678"),        // blue1
+        attributes: HexColor::hex("#427b58"),  // aqua1
+        headings: HexColor::hex("#79740e"),    // green1
+        emphasis: HexColor::hex("#282828"),    // fg1
+        styles: GRUVBOX_STYLES,
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#fbf1c7"),             // bg0
+            line_numbers: HexColor::hex("#a89984"),       // bg4
+            active_line_number: HexColor::hex("#b57614"), // yellow1
+            current_line: HexColor::hex("#ebdbb2"),       // bg1
+            matching_bracket: HexColor::hex("#bdae93"),   // bg3
+            whitespace: HexColor::hex("#d5c4a1"),         // bg2
+            indent_guides: HexColor::hex("#ebdbb2"),      // bg1
+            search_match: HexColor::hex("#b5761440"),     // yellow1
+            error: HexColor::hex("#9d0006"),              // red1
+            warning: HexColor::hex("#b57614"),            // yellow1
+            info: HexColor::hex("#076678"),               // blue1
+            popup: HexColor::hex("#d5c4a1"),              // bg2
+            popup_selection: HexColor::hex("#076678"),    // blue1
+        },
     };
 }
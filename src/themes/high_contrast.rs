@@ -0,0 +1,80 @@
+use super::{ChromeColors, ColorTheme, HexColor, ThemeStyles};
+use std::borrow::Cow;
+
+impl ColorTheme {
+    /// Based on VS Code "Dark High Contrast", every token color passes WCAG AAA on `bg`.
+    pub const HIGH_CONTRAST_DARK: ColorTheme = ColorTheme {
+        name: Cow::Borrowed("High Contrast Dark"),
+        dark: true,
+        bg: HexColor::hex("#000000"),          // black
+        cursor: HexColor::hex("#ffffff"),      // white
+        selection: HexColor::hex("#264f78"),   // selection
+        comments: HexColor::hex("#7ca668"),    // green
+        functions: HexColor::hex("#dcdcaa"),   // yellow
+        keywords: HexColor::hex("#569cd6"),    // blue
+        literals: HexColor::hex("#ffffff"),    // white
+        numerics: HexColor::hex("#b5cea8"),    // light green
+        punctuation: HexColor::hex("#ffffff"), // white
+        strs: HexColor::hex("#ce9178"),        // orange
+        types: HexColor::hex("#4ec9b0"),       // teal
+        special: HexColor::hex("#d7ba7d"),     // gold
+        tags: HexColor::hex("#569cd6"),        // blue
+        attributes: HexColor::hex("#9cdcfe"),  // light blue
+        headings: HexColor::hex("#6fc3df"),    // contrast border
+        emphasis: HexColor::hex("#ffffff"),    // white
+        styles: ThemeStyles::CLASSIC,
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#000000"),             // black
+            line_numbers: HexColor::hex("#ffffff"),       // white
+            active_line_number: HexColor::hex("#f38518"), // active border
+            current_line: HexColor::hex("#000000"),       // black
+            matching_bracket: HexColor::hex("#6fc3df80"), // contrast border
+            whitespace: HexColor::hex("#7ca668"),         // green
+            indent_guides: HexColor::hex("#6fc3df"),      // contrast border
+            search_match: HexColor::hex("#f3851880"),     // active border
+            error: HexColor::hex("#f48771"),              // red
+            warning: HexColor::hex("#ffd700"),            // yellow
+            info: HexColor::hex("#9cdcfe"),               // light blue
+            popup: HexColor::hex("#000000"),              // black
+            popup_selection: HexColor::hex("#f38518"),    // active border
+        },
+    };
+
+    /// Based on VS Code "Light High Contrast", every token color passes WCAG AAA on `bg`.
+    pub const HIGH_CONTRAST_LIGHT: ColorTheme = ColorTheme {
+        name: Cow::Borrowed("High Contrast Light"),
+        dark: false,
+        bg: HexColor::hex("#ffffff"),          // white
+        cursor: HexColor::hex("#000000"),      // black
+        selection: HexColor::hex("#add6ff"),   // selection
+        comments: HexColor::hex("#515151"),    // gray
+        functions: HexColor::hex("#5e2cbc"),   // purple
+        keywords: HexColor::hex("#0f4a85"),    // blue
+        literals: HexColor::hex("#292929"),    // foreground
+        numerics: HexColor::hex("#065c3c"),    // green
+        punctuation: HexColor::hex("#292929"), // foreground
+        strs: HexColor::hex("#9a1b0a"),        // red
+        types: HexColor::hex("#185e73"),       // teal
+        special: HexColor::hex("#811f3f"),     // maroon
+        tags: HexColor::hex("#0b5608"),        // dark green
+        attributes: HexColor::hex("#264f78"),  // navy
+        headings: HexColor::hex("#0f4a85"),    // blue
+        emphasis: HexColor::hex("#292929"),    // foreground
+        styles: ThemeStyles::CLASSIC,
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#ffffff"),             // white
+            line_numbers: HexColor::hex("#292929"),       // foreground
+            active_line_number: HexColor::hex("#006bbd"), // active border
+            current_line: HexColor::hex("#ffffff"),       // white
+            matching_bracket: HexColor::hex("#0f4a8540"), // contrast border
+            whitespace: HexColor::hex("#515151"),         // gray
+            indent_guides: HexColor::hex("#0f4a85"),      // contrast border
+            search_match: HexColor::hex("#0f4a8540"),     // contrast border
+            error: HexColor::hex("#b5200d"),              // red
+            warning: HexColor::hex("#895503"),            // orange
+            info: HexColor::hex("#1a85ff"),               // blue
+            popup: HexColor::hex("#ffffff"),              // white
+            popup_selection: HexColor::hex("#006bbd"),    // active border
+        },
+    };
+}
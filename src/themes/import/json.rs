@@ -0,0 +1,136 @@
+use super::Value;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Parses JSON with comments and trailing commas, as written in VS Code themes.
+pub fn parse(json: &str) -> Result<Value, String> {
+    let mut chars = json.chars().peekable();
+    let value = value(&mut chars)?;
+    skip(&mut chars);
+    match chars.next() {
+        None => Ok(value),
+        Some(c) => Err(format!("unexpected `{c}` after JSON value")),
+    }
+}
+
+// Skips whitespace and comments.
+fn skip(chars: &mut Peekable<Chars>) {
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        if c != '/' {
+            return;
+        }
+        let mut ahead = chars.clone();
+        ahead.next();
+        match ahead.next() {
+            Some('/') => {
+                chars.find(|&c| c == '\n');
+            }
+            Some('*') => {
+                chars.nth(1);
+                let mut star = false;
+                for c in chars.by_ref() {
+                    if star && c == '/' {
+                        break;
+                    }
+                    star = c == '*';
+                }
+            }
+            _ => return,
+        }
+    }
+}
+
+fn value(chars: &mut Peekable<Chars>) -> Result<Value, String> {
+    skip(chars);
+    match chars.peek() {
+        Some('{') => {
+            chars.next();
+            let mut entries = Vec::new();
+            loop {
+                skip(chars);
+                match chars.next() {
+                    Some('}') => return Ok(Value::Dict(entries)),
+                    Some('"') => {
+                        let key = string(chars)?;
+                        skip(chars);
+                        if chars.next() != Some(':') {
+                            return Err(format!("expected `:` after \"{key}\""));
+                        }
+                        entries.push((key, value(chars)?));
+                        skip(chars);
+                        if chars.peek() == Some(&',') {
+                            chars.next();
+                        }
+                    }
+                    _ => return Err(String::from("expected key or `}`")),
+                }
+            }
+        }
+        Some('[') => {
+            chars.next();
+            let mut items = Vec::new();
+            loop {
+                skip(chars);
+                if chars.peek() == Some(&']') {
+                    chars.next();
+                    return Ok(Value::Array(items));
+                }
+                items.push(value(chars)?);
+                skip(chars);
+                if chars.peek() == Some(&',') {
+                    chars.next();
+                }
+            }
+        }
+        Some('"') => {
+            chars.next();
+            string(chars).map(Value::Str)
+        }
+        Some(_) => {
+            let mut word = String::new();
+            while let Some(&c) = chars.peek() {
+                if !(c.is_alphanumeric() || matches!(c, '.' | '-' | '+')) {
+                    break;
+                }
+                word.push(c);
+                chars.next();
+            }
+            match word.as_str() {
+                "true" => Ok(Value::Bool(true)),
+                "false" => Ok(Value::Bool(false)),
+                "null" => Ok(Value::Null),
+                _ if word.parse::<f64>().is_ok() => Ok(Value::Str(word)),
+                _ => Err(format!("unexpected `{word}`")),
+            }
+        }
+        None => Err(String::from("unexpected end of JSON")),
+    }
+}
+
+// Reads a string after its opening quote.
+fn string(chars: &mut Peekable<Chars>) -> Result<String, String> {
+    let mut string = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Ok(string),
+            '\\' => match chars.next() {
+                Some('n') => string.push('\n'),
+                Some('t') => string.push('\t'),
+                Some('r') => string.push('\r'),
+                Some('u') => {
+                    let hex = chars.by_ref().take(4).collect::<String>();
+                    let code = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32);
+                    string.push(code.unwrap_or(char::REPLACEMENT_CHARACTER));
+                }
+                Some(c) => string.push(c),
+                None => break,
+            },
+            c => string.push(c),
+        }
+    }
+    Err(String::from("unterminated string"))
+}
@@ -0,0 +1,483 @@
+mod json;
+mod plist;
+
+use super::{ChromeColors, ColorTheme, FontStyle, HexColor, ThemeError, ThemeStyles};
+use std::borrow::Cow;
+use std::fmt;
+use std::path::Path;
+
+#[derive(Debug)]
+/// Theme file which can't be imported.
+pub enum ImportError {
+    Io(std::io::Error),
+    /// Extension is none of `.json`, `.tmTheme` or `.yaml`.
+    UnknownFormat(String),
+    /// Malformed JSON or property list.
+    Syntax(String),
+    /// Unknown or malformed color, as in `base18` of base16.
+    Theme(ThemeError),
+    MissingColor(String),
+}
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::Io(e) => write!(f, "{e}"),
+            ImportError::UnknownFormat(extension) => {
+                write!(f, "unknown theme format `{extension}`")
+            }
+            ImportError::Syntax(e) => write!(f, "{e}"),
+            ImportError::Theme(e) => write!(f, "{e}"),
+            ImportError::MissingColor(field) => write!(f, "missing color of theme field `{field}`"),
+        }
+    }
+}
+impl std::error::Error for ImportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ImportError::Io(e) => Some(e),
+            ImportError::Theme(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<std::io::Error> for ImportError {
+    fn from(e: std::io::Error) -> Self {
+        ImportError::Io(e)
+    }
+}
+impl From<ThemeError> for ImportError {
+    fn from(e: ThemeError) -> Self {
+        ImportError::Theme(e)
+    }
+}
+
+/// Parsed JSON or property list, numbers are kept as strings.
+enum Value {
+    Null,
+    Bool(bool),
+    Str(String),
+    Array(Vec<Value>),
+    Dict(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Dict(entries) => entries.iter().rev().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    fn str(&self, key: &str) -> Option<&str> {
+        match self.get(key)? {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn color(&self, key: &str) -> Option<HexColor> {
+        self.str(key).and_then(color)
+    }
+
+    // Keys in the order of `ChromeColors` fields, missing ones fall back.
+    fn chrome(&self, keys: [Option<&str>; 13]) -> ChromeColors {
+        let [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12] =
+            keys.map(|key| key.and_then(|key| self.color(key)).unwrap_or_default());
+        ChromeColors {
+            gutter: c0,
+            line_numbers: c1,
+            active_line_number: c2,
+            current_line: c3,
+            matching_bracket: c4,
+            whitespace: c5,
+            indent_guides: c6,
+            search_match: c7,
+            error: c8,
+            warning: c9,
+            info: c10,
+            popup: c11,
+            popup_selection: c12,
+        }
+    }
+
+    fn items(&self) -> &[Value] {
+        match self {
+            Value::Array(items) => items,
+            _ => &[],
+        }
+    }
+}
+
+/// Any notation of `HexColor`, or hexadecimal without `#` as in base16.
+fn color(value: &str) -> Option<HexColor> {
+    let value = value.trim();
+    value.parse().or_else(|_| format!("#{value}").parse()).ok()
+}
+
+fn is_dark(color: HexColor) -> bool {
+    let luma = 299 * u32::from(color.r) + 587 * u32::from(color.g) + 114 * u32::from(color.b);
+    luma < 128_000
+}
+
+/// TextMate scope selector with its settings.
+struct Scope {
+    selector: String,
+    color: Option<HexColor>,
+    style: Option<FontStyle>,
+}
+
+impl Scope {
+    // Rules like `{ "scope": "comment, string", "settings": { "foreground": "#888" } }`
+    fn from_rules(rules: &[Value]) -> Vec<Scope> {
+        let mut scopes = Vec::new();
+        for rule in rules {
+            let Some(settings) = rule.get("settings") else {
+                continue;
+            };
+            let selectors = match rule.get("scope") {
+                Some(Value::Str(s)) => s.split(',').map(str::trim).collect(),
+                Some(Value::Array(items)) => items
+                    .iter()
+                    .filter_map(|item| match item {
+                        Value::Str(s) => Some(s.trim()),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            let font_style = settings.str("fontStyle");
+            let background = settings.color("background");
+            let style = (font_style.is_some() || background.is_some()).then(|| {
+                let mut style = FontStyle {
+                    background,
+                    ..FontStyle::NONE
+                };
+                for word in font_style.unwrap_or_default().split_whitespace() {
+                    style = match word {
+                        "bold" => style.bold(),
+                        "italic" => style.italic(),
+                        "underline" => style.underline(),
+                        "strikethrough" => style.strikethrough(),
+                        _ => style,
+                    };
+                }
+                style
+            });
+            // Descendant selectors like `source.rust string` depend on context
+            scopes.extend(
+                selectors
+                    .into_iter()
+                    .filter(|s| !s.is_empty() && !s.contains(' '))
+                    .map(|selector| Scope {
+                        selector: selector.to_string(),
+                        color: settings.color("foreground"),
+                        style,
+                    }),
+            );
+        }
+        scopes
+    }
+
+    fn matches(&self, scope: &str) -> bool {
+        scope
+            .strip_prefix(self.selector.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    }
+}
+
+// The most specific match of the first candidate scope, later rules win ties.
+fn find<T: Copy>(
+    scopes: &[Scope],
+    candidates: &[&str],
+    field: impl Fn(&Scope) -> Option<T>,
+) -> Option<T> {
+    candidates.iter().find_map(|candidate| {
+        scopes
+            .iter()
+            .filter(|s| s.matches(candidate))
+            .filter_map(|s| field(s).map(|value| (s.selector.len(), value)))
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, value)| value)
+    })
+}
+
+struct Editor {
+    name: String,
+    dark: Option<bool>,
+    bg: Option<HexColor>,
+    fg: Option<HexColor>,
+    cursor: Option<HexColor>,
+    selection: Option<HexColor>,
+    chrome: ChromeColors,
+}
+
+fn from_scopes(editor: Editor, scopes: &[Scope]) -> ColorTheme {
+    let dark = editor.dark.unwrap_or(editor.bg.is_none_or(is_dark));
+    // VS Code defaults
+    let (bg, fg, selection) = if dark {
+        ("#1e1e1e", "#d4d4d4", "#264f78")
+    } else {
+        ("#ffffff", "#000000", "#add6ff")
+    };
+    let bg = editor.bg.unwrap_or(HexColor::hex(bg));
+    let fg = editor.fg.unwrap_or(HexColor::hex(fg));
+    let cursor = editor.cursor.unwrap_or(fg);
+    let selection = editor.selection.unwrap_or(HexColor::hex(selection));
+    let slot = |candidates: &[&str]| {
+        (
+            find(scopes, candidates, |s| s.color).unwrap_or(fg),
+            find(scopes, candidates, |s| s.style).unwrap_or_default(),
+        )
+    };
+    let (comments, comments_style) = slot(&["comment"]);
+    let (functions, functions_style) = slot(&[
+        "entity.name.function",
+        "support.function",
+        "meta.function-call",
+    ]);
+    let (keywords, keywords_style) = slot(&["keyword.control", "keyword", "storage"]);
+    let (literals, literals_style) = slot(&["variable.other", "variable"]);
+    let (numerics, numerics_style) = slot(&["constant.numeric", "constant"]);
+    let (punctuation, punctuation_style) = slot(&["punctuation", "keyword.operator"]);
+    let (strs, strs_style) = slot(&["string"]);
+    let (types, types_style) = slot(&[
+        "entity.name.type",
+        "support.type",
+        "storage.type",
+        "entity.name.class",
+    ]);
+    let (special, special_style) = slot(&[
+        "constant.language",
+        "support.constant",
+        "variable.language",
+        "constant.character",
+    ]);
+    let (tags, tags_style) = slot(&["entity.name.tag"]);
+    let (attributes, attributes_style) = slot(&["entity.other.attribute-name"]);
+    let (headings, headings_style) = slot(&["markup.heading", "entity.name.section"]);
+    let (emphasis, emphasis_style) = slot(&["markup.italic", "markup.bold"]);
+    let hyperlinks_style =
+        find(scopes, &["markup.underline.link"], |s| s.style).unwrap_or(FontStyle::UNDERLINE);
+    ColorTheme {
+        name: Cow::Owned(editor.name),
+        dark,
+        bg,
+        cursor,
+        selection,
+        comments,
+        functions,
+        keywords,
+        literals,
+        numerics,
+        punctuation,
+        strs,
+        types,
+        special,
+        tags,
+        attributes,
+        headings,
+        emphasis,
+        styles: ThemeStyles {
+            comments: comments_style,
+            functions: functions_style,
+            keywords: keywords_style,
+            literals: literals_style,
+            numerics: numerics_style,
+            punctuation: punctuation_style,
+            strs: strs_style,
+            types: types_style,
+            special: special_style,
+            tags: tags_style,
+            attributes: attributes_style,
+            headings: headings_style,
+            emphasis: emphasis_style,
+            hyperlinks: hyperlinks_style,
+        },
+        chrome: editor.chrome,
+    }
+}
+
+impl ColorTheme {
+    /// Converts VS Code theme JSON, mapping `tokenColors` scopes onto theme slots.
+    pub fn from_vscode(json: &str) -> Result<Self, ImportError> {
+        let theme = json::parse(json).map_err(ImportError::Syntax)?;
+        let colors = theme.get("colors").unwrap_or(&Value::Null);
+        let editor = Editor {
+            name: theme.str("name").unwrap_or_default().to_string(),
+            dark: theme.str("type").map(|ty| !ty.contains("light")),
+            bg: colors.color("editor.background"),
+            fg: colors.color("editor.foreground"),
+            cursor: colors.color("editorCursor.foreground"),
+            selection: colors.color("editor.selectionBackground"),
+            chrome: colors.chrome([
+                Some("editorGutter.background"),
+                Some("editorLineNumber.foreground"),
+                Some("editorLineNumber.activeForeground"),
+                Some("editor.lineHighlightBackground"),
+                Some("editorBracketMatch.background"),
+                Some("editorWhitespace.foreground"),
+                Some("editorIndentGuide.background1"),
+                Some("editor.findMatchHighlightBackground"),
+                Some("editorError.foreground"),
+                Some("editorWarning.foreground"),
+                Some("editorInfo.foreground"),
+                Some("editorSuggestWidget.background"),
+                Some("editorSuggestWidget.selectedBackground"),
+            ]),
+        };
+        let rules = theme.get("tokenColors").map_or(&[][..], Value::items);
+        Ok(from_scopes(editor, &Scope::from_rules(rules)))
+    }
+
+    /// Converts TextMate `.tmTheme` property list.
+    pub fn from_tmtheme(plist: &str) -> Result<Self, ImportError> {
+        let theme = plist::parse(plist).map_err(ImportError::Syntax)?;
+        let rules = theme.get("settings").map_or(&[][..], Value::items);
+        // Editor colors are in the rule without scope
+        let global = rules
+            .iter()
+            .find(|rule| rule.get("scope").is_none())
+            .and_then(|rule| rule.get("settings"))
+            .unwrap_or(&Value::Null);
+        let editor = Editor {
+            name: theme.str("name").unwrap_or_default().to_string(),
+            dark: None,
+            bg: global.color("background"),
+            fg: global.color("foreground"),
+            cursor: global.color("caret"),
+            selection: global.color("selection"),
+            // No diagnostics colors in tmTheme
+            chrome: global.chrome([
+                Some("gutter"),
+                Some("gutterForeground"),
+                Some("gutterForegroundHighlight"),
+                Some("lineHighlight"),
+                Some("bracketsForeground"),
+                Some("invisibles"),
+                Some("guide"),
+                Some("findHighlight"),
+                None,
+                None,
+                None,
+                Some("popupBackground"),
+                Some("popupSelection"),
+            ]),
+        };
+        Ok(from_scopes(editor, &Scope::from_rules(rules)))
+    }
+
+    /// Converts base16 or base24 YAML scheme, following the base16 styling guidelines.
+    /// Bright red and blue of base24 color errors and info, falling back to base16 colors.
+    pub fn from_base16(yaml: &str) -> Result<Self, ImportError> {
+        let mut name = String::new();
+        let mut dark = None;
+        let mut palette = [None; 24];
+        for line in yaml.lines() {
+            let Some((key, value)) = line.trim().split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            let value = match value.strip_prefix(['"', '\'']) {
+                Some(quoted) => quoted.split(['"', '\'']).next().unwrap_or_default(),
+                None => value.split(" #").next().unwrap_or_default().trim(),
+            };
+            match key.trim() {
+                "scheme" | "name" => name = value.to_string(),
+                "variant" => dark = Some(value != "light"),
+                key => {
+                    let Some(i) = key
+                        .strip_prefix("base")
+                        .filter(|i| i.len() >= 2 && i.chars().all(|c| c.is_ascii_hexdigit()))
+                    else {
+                        continue;
+                    };
+                    // `base00` to `base0F`, and `base10` to `base17` of base24
+                    let slot = usize::from_str_radix(i, 16)
+                        .ok()
+                        .filter(|_| i.len() == 2)
+                        .and_then(|i| palette.get_mut(i))
+                        .ok_or_else(|| ThemeError::UnknownField(key.to_string()))?;
+                    *slot = Some(color(value).ok_or_else(|| ThemeError::MalformedColor {
+                        field: key.to_string(),
+                        value: value.to_string(),
+                    })?);
+                }
+            }
+        }
+        let mut base = [HexColor::NONE; 24];
+        for (i, color) in palette.into_iter().enumerate().take(16) {
+            base[i] = color.ok_or_else(|| ImportError::MissingColor(format!("base0{i:X}")))?;
+        }
+        // Base24 colors missing from base16 schemes, as recommended by the base24 spec
+        let fallbacks = [0x0, 0x0, 0x8, 0xA, 0xB, 0xC, 0xD, 0xE];
+        for (i, fallback) in (0x10..).zip(fallbacks) {
+            base[i] = palette[i].unwrap_or(base[fallback]);
+        }
+        Ok(ColorTheme {
+            name: Cow::Owned(name),
+            dark: dark.unwrap_or(is_dark(base[0])),
+            bg: base[0x0],
+            cursor: base[0x5],
+            selection: base[0x2],
+            comments: base[0x3],
+            functions: base[0xD],
+            keywords: base[0xE],
+            literals: base[0x5],
+            numerics: base[0x9],
+            punctuation: base[0x5],
+            strs: base[0xB],
+            types: base[0xA],
+            special: base[0xC],
+            tags: base[0x8],
+            attributes: base[0xA],
+            headings: base[0xD],
+            emphasis: base[0xE],
+            styles: ThemeStyles {
+                headings: FontStyle::BOLD,
+                emphasis: FontStyle::ITALIC,
+                hyperlinks: FontStyle::UNDERLINE,
+                ..ThemeStyles::NONE
+            },
+            chrome: ChromeColors {
+                gutter: base[0x1],
+                line_numbers: base[0x4],
+                active_line_number: base[0x5],
+                current_line: base[0x1],
+                matching_bracket: base[0x3],
+                whitespace: base[0x3],
+                indent_guides: base[0x2],
+                search_match: base[0xA],
+                error: base[0x12],
+                warning: base[0x9],
+                info: base[0x16],
+                popup: base[0x1],
+                popup_selection: base[0x2],
+            },
+        })
+    }
+
+    /// Loads VS Code `.json`, TextMate `.tmTheme` or base16 `.yaml` theme by its extension.
+    /// Theme without name is named after the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ImportError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        let theme = match extension.to_lowercase().as_str() {
+            "json" | "jsonc" => ColorTheme::from_vscode(&text),
+            "tmtheme" | "plist" | "xml" => ColorTheme::from_tmtheme(&text),
+            "yaml" | "yml" => ColorTheme::from_base16(&text),
+            _ => Err(ImportError::UnknownFormat(extension.to_string())),
+        }?;
+        if theme.name.is_empty() {
+            let stem = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or_default();
+            return Ok(theme.with_name(stem.to_string()));
+        }
+        Ok(theme)
+    }
+}
@@ -0,0 +1,126 @@
+use super::Value;
+
+/// Parses XML property list, as written in TextMate `.tmTheme` files.
+pub fn parse(plist: &str) -> Result<Value, String> {
+    let mut tags = Tags { rest: plist };
+    loop {
+        match tags.next() {
+            Some(Tag::Open(name)) if name != "plist" => return value(&mut tags, name),
+            Some(_) => continue,
+            None => return Err(String::from("property list is empty")),
+        }
+    }
+}
+
+enum Tag<'a> {
+    Open(&'a str),
+    Close(&'a str),
+    Empty(&'a str),
+    Text(&'a str),
+}
+
+struct Tags<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Tags<'a> {
+    type Item = Tag<'a>;
+
+    fn next(&mut self) -> Option<Tag<'a>> {
+        loop {
+            let start = self.rest.find('<')?;
+            let text = &self.rest[..start];
+            if !text.trim().is_empty() {
+                self.rest = &self.rest[start..];
+                return Some(Tag::Text(text));
+            }
+            let tag = &self.rest[start..];
+            // Declarations, comments and doctype are skipped
+            if let Some(comment) = tag.strip_prefix("<!--") {
+                self.rest = comment.split_once("-->").map_or("", |(_, rest)| rest);
+                continue;
+            }
+            let end = tag.find('>')?;
+            self.rest = &tag[end + 1..];
+            let inner = &tag[1..end];
+            if inner.starts_with(['?', '!']) {
+                continue;
+            }
+            return Some(if let Some(name) = inner.strip_prefix('/') {
+                Tag::Close(name.trim())
+            } else if let Some(name) = inner.strip_suffix('/') {
+                Tag::Empty(name.trim())
+            } else {
+                Tag::Open(inner.split_whitespace().next().unwrap_or_default())
+            });
+        }
+    }
+}
+
+fn value(tags: &mut Tags, name: &str) -> Result<Value, String> {
+    match name {
+        "dict" => {
+            let mut entries = Vec::new();
+            loop {
+                match tags.next() {
+                    Some(Tag::Close("dict")) => return Ok(Value::Dict(entries)),
+                    Some(Tag::Open("key")) => {
+                        let key = text(tags, "key")?;
+                        entries.push((key, item(tags)?));
+                    }
+                    _ => return Err(String::from("expected `<key>` in `<dict>`")),
+                }
+            }
+        }
+        "array" => {
+            let mut items = Vec::new();
+            loop {
+                match tags.next() {
+                    Some(Tag::Close("array")) => return Ok(Value::Array(items)),
+                    Some(Tag::Open(name)) => items.push(value(tags, name)?),
+                    Some(Tag::Empty(name)) => items.push(empty(name)),
+                    _ => return Err(String::from("unterminated `<array>`")),
+                }
+            }
+        }
+        _ => text(tags, name).map(Value::Str),
+    }
+}
+
+fn item(tags: &mut Tags) -> Result<Value, String> {
+    match tags.next() {
+        Some(Tag::Open(name)) => value(tags, name),
+        Some(Tag::Empty(name)) => Ok(empty(name)),
+        _ => Err(String::from("expected value after `<key>`")),
+    }
+}
+
+fn empty(name: &str) -> Value {
+    match name {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        "dict" => Value::Dict(Vec::new()),
+        "array" => Value::Array(Vec::new()),
+        _ => Value::Str(String::new()),
+    }
+}
+
+// Reads text until the closing tag.
+fn text(tags: &mut Tags, name: &str) -> Result<String, String> {
+    let mut text = String::new();
+    loop {
+        match tags.next() {
+            Some(Tag::Text(t)) => text.push_str(t),
+            Some(Tag::Close(close)) if close == name => return Ok(unescape(text.trim())),
+            _ => return Err(format!("unterminated `<{name}>`")),
+        }
+    }
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
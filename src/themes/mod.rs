@@ -1,18 +1,30 @@
 #![allow(dead_code)]
 pub mod ayu;
+mod color;
+#[cfg(feature = "editor")]
+#[cfg(feature = "egui")]
+mod editor;
+mod export;
 pub mod github;
 pub mod gruvbox;
+pub mod high_contrast;
+mod import;
 pub mod sonokai;
 
 use super::syntax::TokenType;
+pub use color::HexColor;
+#[cfg(feature = "editor")]
 #[cfg(feature = "egui")]
-use egui::Color32;
-
+pub use editor::{ThemeEditor, ThemeExport};
 #[cfg(feature = "egui")]
-pub const ERROR_COLOR: Color32 = Color32::from_rgb(255, 0, 255);
+use egui::Color32;
+pub(crate) use export::{css_color, css_declarations};
+pub use import::ImportError;
+use std::borrow::Cow;
+use std::fmt;
 
 /// Array of default themes.
-pub const DEFAULT_THEMES: [ColorTheme; 8] = [
+pub const DEFAULT_THEMES: [ColorTheme; 10] = [
     ColorTheme::AYU,
     ColorTheme::AYU_MIRAGE,
     ColorTheme::AYU_DARK,
@@ -21,38 +33,249 @@ pub const DEFAULT_THEMES: [ColorTheme; 8] = [
     ColorTheme::GRUVBOX,
     ColorTheme::GRUVBOX_LIGHT,
     ColorTheme::SONOKAI,
+    ColorTheme::HIGH_CONTRAST_DARK,
+    ColorTheme::HIGH_CONTRAST_LIGHT,
 ];
 
-#[cfg(feature = "egui")]
-fn color_from_hex(hex: &str) -> Option<Color32> {
-    if hex == "none" {
-        return Some(Color32::from_rgba_premultiplied(255, 0, 255, 0));
-    }
-    let rgb = (1..hex.len())
-        .step_by(2)
-        .filter_map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
-        .collect::<Vec<u8>>();
-    let color = Color32::from_rgb(*rgb.first()?, *rgb.get(1)?, *rgb.get(2)?);
-    Some(color)
+#[derive(Default, Hash, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+/// Font style of a token type with optional background.
+pub struct FontStyle {
+    /// Rendered by exporters only, egui has no bold weight of the monospace font.
+    pub bold: bool,
+    pub italic: bool,
+    pub underline: bool,
+    pub strikethrough: bool,
+    pub background: Option<HexColor>,
 }
+impl FontStyle {
+    pub const NONE: FontStyle = FontStyle {
+        bold: false,
+        italic: false,
+        underline: false,
+        strikethrough: false,
+        background: None,
+    };
+    pub const BOLD: FontStyle = FontStyle::NONE.bold();
+    pub const ITALIC: FontStyle = FontStyle::NONE.italic();
+    pub const UNDERLINE: FontStyle = FontStyle::NONE.underline();
+
+    pub const fn bold(self) -> Self {
+        FontStyle { bold: true, ..self }
+    }
+
+    pub const fn italic(self) -> Self {
+        FontStyle {
+            italic: true,
+            ..self
+        }
+    }
+
+    pub const fn underline(self) -> Self {
+        FontStyle {
+            underline: true,
+            ..self
+        }
+    }
 
-#[derive(Hash, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
-/// Colors in hexadecimal notation as used in HTML and CSS.
+    pub const fn strikethrough(self) -> Self {
+        FontStyle {
+            strikethrough: true,
+            ..self
+        }
+    }
+
+    pub const fn with_background(self, background: HexColor) -> Self {
+        FontStyle {
+            background: Some(background),
+            ..self
+        }
+    }
+
+    pub const fn is_plain(&self) -> bool {
+        !(self.bold || self.italic || self.underline || self.strikethrough)
+            && self.background.is_none()
+    }
+}
+
+#[derive(Default, Hash, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+/// Font styles for the same slots as colors of `ColorTheme`.
+pub struct ThemeStyles {
+    pub comments: FontStyle,
+    pub functions: FontStyle,
+    pub keywords: FontStyle,
+    pub literals: FontStyle,
+    pub numerics: FontStyle,
+    pub punctuation: FontStyle,
+    pub strs: FontStyle,
+    pub types: FontStyle,
+    pub special: FontStyle,
+    pub tags: FontStyle,
+    pub attributes: FontStyle,
+    pub headings: FontStyle,
+    pub emphasis: FontStyle,
+    /// Hyperlinks are colored as `special`.
+    pub hyperlinks: FontStyle,
+}
+impl ThemeStyles {
+    pub const NONE: ThemeStyles = ThemeStyles {
+        comments: FontStyle::NONE,
+        functions: FontStyle::NONE,
+        keywords: FontStyle::NONE,
+        literals: FontStyle::NONE,
+        numerics: FontStyle::NONE,
+        punctuation: FontStyle::NONE,
+        strs: FontStyle::NONE,
+        types: FontStyle::NONE,
+        special: FontStyle::NONE,
+        tags: FontStyle::NONE,
+        attributes: FontStyle::NONE,
+        headings: FontStyle::NONE,
+        emphasis: FontStyle::NONE,
+        hyperlinks: FontStyle::NONE,
+    };
+    /// Italic comments, bold headings, italic emphasis and underlined hyperlinks.
+    pub const CLASSIC: ThemeStyles = ThemeStyles {
+        comments: FontStyle::ITALIC,
+        headings: FontStyle::BOLD,
+        emphasis: FontStyle::ITALIC,
+        hyperlinks: FontStyle::UNDERLINE,
+        ..ThemeStyles::NONE
+    };
+
+    /// Style slots by name, in the order of theme colors.
+    fn all_mut(&mut self) -> [(&'static str, &mut FontStyle); 14] {
+        [
+            ("comments", &mut self.comments),
+            ("functions", &mut self.functions),
+            ("keywords", &mut self.keywords),
+            ("literals", &mut self.literals),
+            ("numerics", &mut self.numerics),
+            ("punctuation", &mut self.punctuation),
+            ("strs", &mut self.strs),
+            ("types", &mut self.types),
+            ("special", &mut self.special),
+            ("tags", &mut self.tags),
+            ("attributes", &mut self.attributes),
+            ("headings", &mut self.headings),
+            ("emphasis", &mut self.emphasis),
+            ("hyperlinks", &mut self.hyperlinks),
+        ]
+    }
+}
+
+#[derive(Default, Hash, Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+/// Colors of the editor around the code, `none` falls back to a token color.
+///
+/// `CodeEditor` paints the gutter, line numbers and completer popup. The other slots
+/// are carried for exporters and apps painting their own decorations.
+pub struct ChromeColors {
+    /// Falls back to `bg`.
+    pub gutter: HexColor,
+    /// Falls back to `comments`.
+    pub line_numbers: HexColor,
+    /// Falls back to `literals`.
+    pub active_line_number: HexColor,
+    /// Highlighted lines of `HtmlRenderer` and the stylesheet, falls back to `bg`.
+    pub current_line: HexColor,
+    /// Falls back to `selection`.
+    pub matching_bracket: HexColor,
+    /// Falls back to `comments`.
+    pub whitespace: HexColor,
+    /// Falls back to `comments`.
+    pub indent_guides: HexColor,
+    /// Falls back to `selection`.
+    pub search_match: HexColor,
+    /// Falls back to `keywords`.
+    pub error: HexColor,
+    /// Falls back to `numerics`.
+    pub warning: HexColor,
+    /// Falls back to `functions`.
+    pub info: HexColor,
+    /// Completer popup background, falls back to `bg`.
+    pub popup: HexColor,
+    /// Selected completion, falls back to `literals`.
+    pub popup_selection: HexColor,
+}
+impl ChromeColors {
+    pub const NONE: ChromeColors = ChromeColors {
+        gutter: HexColor::NONE,
+        line_numbers: HexColor::NONE,
+        active_line_number: HexColor::NONE,
+        current_line: HexColor::NONE,
+        matching_bracket: HexColor::NONE,
+        whitespace: HexColor::NONE,
+        indent_guides: HexColor::NONE,
+        search_match: HexColor::NONE,
+        error: HexColor::NONE,
+        warning: HexColor::NONE,
+        info: HexColor::NONE,
+        popup: HexColor::NONE,
+        popup_selection: HexColor::NONE,
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Theme field which can't be set.
+pub enum ThemeError {
+    UnknownField(String),
+    MalformedColor { field: String, value: String },
+}
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThemeError::UnknownField(field) => write!(f, "unknown theme field `{field}`"),
+            ThemeError::MalformedColor { field, value } => {
+                write!(f, "malformed color `{value}` of theme field `{field}`")
+            }
+        }
+    }
+}
+impl std::error::Error for ThemeError {}
+
+const fn or(color: HexColor, fallback: HexColor) -> HexColor {
+    if color.is_none() { fallback } else { color }
+}
+
+// Colors which aren't token slots.
+fn is_editor_field(field: &str) -> bool {
+    matches!(field, "bg" | "cursor" | "selection") || field.starts_with("chrome.")
+}
+
+#[derive(Hash, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+/// Colors parsed from hexadecimal notation as used in HTML and CSS.
+///
+/// Built-in themes are constants, while owned ones can be loaded with `serde` from TOML or JSON.
+/// Owned names make themes `Clone` but not `Copy`, so constants are passed as `theme.to_owned()`.
 pub struct ColorTheme {
-    pub name: &'static str,
+    pub name: Cow<'static, str>,
     pub dark: bool,
-    pub bg: &'static str,
-    pub cursor: &'static str,
-    pub selection: &'static str,
-    pub comments: &'static str,
-    pub functions: &'static str,
-    pub keywords: &'static str,
-    pub literals: &'static str,
-    pub numerics: &'static str,
-    pub punctuation: &'static str,
-    pub strs: &'static str,
-    pub types: &'static str,
-    pub special: &'static str,
+    pub bg: HexColor,
+    pub cursor: HexColor,
+    pub selection: HexColor,
+    pub comments: HexColor,
+    pub functions: HexColor,
+    pub keywords: HexColor,
+    pub literals: HexColor,
+    pub numerics: HexColor,
+    pub punctuation: HexColor,
+    pub strs: HexColor,
+    pub types: HexColor,
+    pub special: HexColor,
+    pub tags: HexColor,
+    pub attributes: HexColor,
+    pub headings: HexColor,
+    pub emphasis: HexColor,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub styles: ThemeStyles,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub chrome: ChromeColors,
 }
 impl Default for ColorTheme {
     fn default() -> Self {
@@ -61,7 +284,14 @@ impl Default for ColorTheme {
 }
 impl ColorTheme {
     pub fn name(&self) -> &str {
-        self.name
+        &self.name
+    }
+
+    pub fn with_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        ColorTheme {
+            name: name.into(),
+            ..self
+        }
     }
 
     pub fn is_dark(&self) -> bool {
@@ -70,24 +300,262 @@ impl ColorTheme {
 
     #[cfg(feature = "egui")]
     pub fn bg(&self) -> Color32 {
-        color_from_hex(self.bg).unwrap_or(ERROR_COLOR)
+        self.bg.into()
     }
 
     #[cfg(feature = "egui")]
     pub fn cursor(&self) -> Color32 {
-        color_from_hex(self.cursor).unwrap_or(ERROR_COLOR)
+        self.cursor.into()
     }
 
     #[cfg(feature = "egui")]
     pub fn selection(&self) -> Color32 {
-        color_from_hex(self.selection).unwrap_or(ERROR_COLOR)
+        self.selection.into()
+    }
+
+    fn fields(&mut self) -> [(&'static str, &mut HexColor); 29] {
+        [
+            ("bg", &mut self.bg),
+            ("cursor", &mut self.cursor),
+            ("selection", &mut self.selection),
+            ("comments", &mut self.comments),
+            ("functions", &mut self.functions),
+            ("keywords", &mut self.keywords),
+            ("literals", &mut self.literals),
+            ("numerics", &mut self.numerics),
+            ("punctuation", &mut self.punctuation),
+            ("strs", &mut self.strs),
+            ("types", &mut self.types),
+            ("special", &mut self.special),
+            ("tags", &mut self.tags),
+            ("attributes", &mut self.attributes),
+            ("headings", &mut self.headings),
+            ("emphasis", &mut self.emphasis),
+            ("chrome.gutter", &mut self.chrome.gutter),
+            ("chrome.line_numbers", &mut self.chrome.line_numbers),
+            (
+                "chrome.active_line_number",
+                &mut self.chrome.active_line_number,
+            ),
+            ("chrome.current_line", &mut self.chrome.current_line),
+            ("chrome.matching_bracket", &mut self.chrome.matching_bracket),
+            ("chrome.whitespace", &mut self.chrome.whitespace),
+            ("chrome.indent_guides", &mut self.chrome.indent_guides),
+            ("chrome.search_match", &mut self.chrome.search_match),
+            ("chrome.error", &mut self.chrome.error),
+            ("chrome.warning", &mut self.chrome.warning),
+            ("chrome.info", &mut self.chrome.info),
+            ("chrome.popup", &mut self.chrome.popup),
+            ("chrome.popup_selection", &mut self.chrome.popup_selection),
+        ]
+    }
+
+    /// Color fields by name, chrome ones prefixed with `chrome.`.
+    pub fn colors(&self) -> Vec<(&'static str, HexColor)> {
+        let mut theme = self.clone();
+        theme
+            .fields()
+            .map(|(field, color)| (field, *color))
+            .to_vec()
+    }
+
+    /// Sets color field by name from any notation supported by `HexColor`.
+    pub fn set_color(&mut self, field: &str, value: &str) -> Result<(), ThemeError> {
+        let Some((_, color)) = self.fields().into_iter().find(|(name, _)| *name == field) else {
+            return Err(ThemeError::UnknownField(field.to_string()));
+        };
+        *color = value.parse().map_err(|_| ThemeError::MalformedColor {
+            field: field.to_string(),
+            value: value.to_string(),
+        })?;
+        Ok(())
     }
 
+    /// Sets all color fields, reporting every broken one.
+    pub fn with_colors<'a>(
+        mut self,
+        colors: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Result<Self, Vec<ThemeError>> {
+        let errors = colors
+            .into_iter()
+            .filter_map(|(field, value)| self.set_color(field, value).err())
+            .collect::<Vec<_>>();
+        if errors.is_empty() {
+            Ok(self)
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// WCAG AA contrast for normal text.
+    pub const CONTRAST_AA: f32 = 4.5;
+    /// WCAG AAA contrast for normal text.
+    pub const CONTRAST_AAA: f32 = 7.0;
+
+    /// Contrast ratio of every token color against `bg`.
+    pub fn contrast_ratios(&self) -> Vec<(&'static str, f32)> {
+        self.colors()
+            .into_iter()
+            .filter(|(field, _)| !is_editor_field(field))
+            .map(|(field, color)| (field, color.contrast(self.bg)))
+            .collect()
+    }
+
+    /// Token colors with contrast against `bg` below `target`.
+    pub fn contrast_failures(&self, target: f32) -> Vec<(&'static str, f32)> {
+        self.contrast_ratios()
+            .into_iter()
+            .filter(|(_, ratio)| *ratio < target)
+            .collect()
+    }
+
+    /// Moves token colors failing `target` towards white or black, whichever stands out from `bg`,
+    /// just far enough to pass. Targets above what `bg` allows end at pure white or black.
+    pub fn with_contrast(mut self, target: f32) -> Self {
+        let bg = self.bg;
+        let (white, black) = (HexColor::rgb(255, 255, 255), HexColor::rgb(0, 0, 0));
+        let extreme = if bg.contrast(white) >= bg.contrast(black) {
+            white
+        } else {
+            black
+        };
+        for (field, color) in self.fields() {
+            if is_editor_field(field) || color.contrast(bg) >= target {
+                continue;
+            }
+            let (mut low, mut high) = (0.0, 1.0);
+            for _ in 0..16 {
+                let t = (low + high) / 2.0;
+                if color.mix(extreme, t).contrast(bg) >= target {
+                    high = t;
+                } else {
+                    low = t;
+                }
+            }
+            *color = color.mix(extreme, high);
+        }
+        self
+    }
+
+    /// Light counterpart of a dark theme and vice versa, perceptual lightness of every color
+    /// is inverted while hues are kept.
+    pub fn inverted(&self) -> Self {
+        let mut theme = self.clone();
+        for (_, color) in theme.fields() {
+            *color = color.invert_lightness();
+        }
+        for (_, style) in theme.styles.all_mut() {
+            style.background = style.background.map(|bg| bg.invert_lightness());
+        }
+        let base = self
+            .name
+            .trim_end_matches(" Dark")
+            .trim_end_matches(" Light");
+        let variant = if self.dark { "Light" } else { "Dark" };
+        ColorTheme {
+            name: Cow::Owned(format!("{base} {variant}")),
+            dark: !self.dark,
+            ..theme
+        }
+    }
+
+    /// Light variant, the theme itself if it's already light.
+    pub fn to_light(&self) -> Self {
+        if self.dark {
+            self.inverted()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Dark variant, the theme itself if it's already dark.
+    pub fn to_dark(&self) -> Self {
+        if self.dark {
+            self.clone()
+        } else {
+            self.inverted()
+        }
+    }
+
+    /// Theme blending into the app with background, text and selection of egui `Visuals`.
+    /// Token colors are spread around the hue of the selection stroke and meet WCAG AA.
+    #[cfg(feature = "egui")]
+    pub fn from_style(style: &egui::Style) -> Self {
+        let visuals = &style.visuals;
+        let dark = visuals.dark_mode;
+        let bg = HexColor::from(visuals.text_edit_bg_color());
+        let text = HexColor::from(visuals.text_color());
+        let weak = HexColor::from(visuals.weak_text_color());
+        let weak = bg.mix(weak, weak.a as f32 / 255.0);
+        let hue = HexColor::from(visuals.selection.stroke.color).hue();
+        let lightness = if dark { 0.8 } else { 0.5 };
+        let token = |turn: f32| HexColor::oklch(lightness, 0.12, hue + turn);
+        ColorTheme {
+            name: Cow::Borrowed(if dark { "egui Dark" } else { "egui Light" }),
+            dark,
+            bg,
+            cursor: visuals.text_cursor.stroke.color.into(),
+            selection: visuals.selection.bg_fill.into(),
+            comments: weak,
+            functions: token(60.0),
+            keywords: token(0.0),
+            literals: text,
+            numerics: token(180.0),
+            punctuation: text,
+            strs: token(120.0),
+            types: token(240.0),
+            special: visuals.hyperlink_color.into(),
+            tags: token(300.0),
+            attributes: token(30.0),
+            headings: visuals.strong_text_color().into(),
+            emphasis: text,
+            styles: ThemeStyles::CLASSIC,
+            chrome: ChromeColors {
+                gutter: bg,
+                line_numbers: weak,
+                active_line_number: text,
+                current_line: visuals.faint_bg_color.into(),
+                indent_guides: visuals.widgets.noninteractive.bg_stroke.color.into(),
+                error: visuals.error_fg_color.into(),
+                warning: visuals.warn_fg_color.into(),
+                popup: visuals.window_fill.into(),
+                ..ChromeColors::NONE
+            },
+        }
+        .with_contrast(ColorTheme::CONTRAST_AA)
+    }
+
+    /// Editor chrome with fallbacks filled in.
+    pub const fn chrome_colors(&self) -> ChromeColors {
+        let chrome = self.chrome;
+        ChromeColors {
+            gutter: or(chrome.gutter, self.bg),
+            line_numbers: or(chrome.line_numbers, self.comments),
+            active_line_number: or(chrome.active_line_number, self.literals),
+            current_line: or(chrome.current_line, self.bg),
+            matching_bracket: or(chrome.matching_bracket, self.selection),
+            whitespace: or(chrome.whitespace, self.comments),
+            indent_guides: or(chrome.indent_guides, self.comments),
+            search_match: or(chrome.search_match, self.selection),
+            error: or(chrome.error, self.keywords),
+            warning: or(chrome.warning, self.numerics),
+            info: or(chrome.info, self.functions),
+            popup: or(chrome.popup, self.bg),
+            popup_selection: or(chrome.popup_selection, self.literals),
+        }
+    }
+
+    /// Applies the theme to egui visuals. Of the chrome only gutter, popup and diagnostics colors
+    /// map onto egui, see `ChromeColors` for the rest.
     #[cfg(feature = "egui")]
     pub fn modify_style(&self, ui: &mut egui::Ui, fontsize: f32) {
+        let chrome = self.chrome_colors();
         let style = ui.style_mut();
         style.visuals.widgets.noninteractive.bg_fill = self.bg();
-        style.visuals.window_fill = self.bg();
+        style.visuals.window_fill = chrome.popup.into();
+        style.visuals.code_bg_color = chrome.gutter.into();
+        style.visuals.error_fg_color = chrome.error.into();
+        style.visuals.warn_fg_color = chrome.warning.into();
         style.visuals.selection.stroke.color = self.cursor();
         style.visuals.selection.bg_fill = self.selection();
         style.visuals.extreme_bg_color = self.bg();
@@ -95,10 +563,14 @@ impl ColorTheme {
         style.visuals.text_cursor.stroke.width = fontsize * 0.1;
     }
 
-    pub const fn type_color_str(&self, ty: TokenType) -> &'static str {
+    pub const fn type_hex_color(&self, ty: TokenType) -> HexColor {
         match ty {
+            TokenType::Attribute => self.attributes,
+            TokenType::Column(column) => self.column_hex_color(column),
             TokenType::Comment(_) => self.comments,
-            TokenType::Function => self.functions,
+            TokenType::Emphasis => self.emphasis,
+            TokenType::Function | TokenType::Key => self.functions,
+            TokenType::Heading => self.headings,
             TokenType::Keyword => self.keywords,
             TokenType::Literal => self.literals,
             TokenType::Hyperlink => self.special,
@@ -106,38 +578,80 @@ impl ColorTheme {
             TokenType::Punctuation(_) => self.punctuation,
             TokenType::Special => self.special,
             TokenType::Str(_) => self.strs,
+            TokenType::Tag => self.tags,
             TokenType::Type => self.types,
             TokenType::Whitespace(_) | TokenType::Unknown => self.comments,
         }
     }
 
-    #[cfg(feature = "egui")]
-    pub fn type_color(&self, ty: TokenType) -> Color32 {
+    pub const fn type_style(&self, ty: TokenType) -> FontStyle {
         match ty {
-            TokenType::Comment(_) => color_from_hex(self.comments),
-            TokenType::Function => color_from_hex(self.functions),
-            TokenType::Keyword => color_from_hex(self.keywords),
-            TokenType::Literal => color_from_hex(self.literals),
-            TokenType::Hyperlink => color_from_hex(self.special),
-            TokenType::Numeric(_) => color_from_hex(self.numerics),
-            TokenType::Punctuation(_) => color_from_hex(self.punctuation),
-            TokenType::Special => color_from_hex(self.special),
-            TokenType::Str(_) => color_from_hex(self.strs),
-            TokenType::Type => color_from_hex(self.types),
-            TokenType::Whitespace(_) | TokenType::Unknown => color_from_hex(self.comments),
+            TokenType::Attribute => self.styles.attributes,
+            TokenType::Column(_) | TokenType::Whitespace(_) | TokenType::Unknown => FontStyle::NONE,
+            TokenType::Comment(_) => self.styles.comments,
+            TokenType::Emphasis => self.styles.emphasis,
+            TokenType::Function | TokenType::Key => self.styles.functions,
+            TokenType::Heading => self.styles.headings,
+            TokenType::Keyword => self.styles.keywords,
+            TokenType::Literal => self.styles.literals,
+            TokenType::Hyperlink => self.styles.hyperlinks,
+            TokenType::Numeric(_) => self.styles.numerics,
+            TokenType::Punctuation(_) => self.styles.punctuation,
+            TokenType::Special => self.styles.special,
+            TokenType::Str(_) => self.styles.strs,
+            TokenType::Tag => self.styles.tags,
+            TokenType::Type => self.styles.types,
         }
-        .unwrap_or(ERROR_COLOR)
     }
 
+    /// Rainbow colors for CSV-like columns.
+    pub const fn column_hex_color(&self, column: usize) -> HexColor {
+        let rainbow = [
+            self.keywords,
+            self.functions,
+            self.types,
+            self.strs,
+            self.special,
+            self.numerics,
+        ];
+        rainbow[column % rainbow.len()]
+    }
+
+    /// Token color in hexadecimal notation, as in `#fa8d3e`.
+    #[deprecated(note = "use `type_hex_color`, colors are parsed into `HexColor`")]
+    pub fn type_color_str(&self, ty: TokenType) -> String {
+        self.type_hex_color(ty).to_string()
+    }
+
+    /// Column color in hexadecimal notation, as in `#fa8d3e`.
+    #[deprecated(note = "use `column_hex_color`, colors are parsed into `HexColor`")]
+    pub fn column_color_str(&self, column: usize) -> String {
+        self.column_hex_color(column).to_string()
+    }
+
+    #[cfg(feature = "egui")]
+    pub fn type_color(&self, ty: TokenType) -> Color32 {
+        self.type_hex_color(ty).into()
+    }
+
+    /// Background of the token type, transparent if the style has none.
+    #[cfg(feature = "egui")]
+    pub fn type_background(&self, ty: TokenType) -> Color32 {
+        self.type_style(ty)
+            .background
+            .map_or(Color32::TRANSPARENT, Color32::from)
+    }
+
+    /// Single color theme, colors are given as in `HexColor::hex("#ffffff")`.
     pub fn monocolor(
         dark: bool,
-        bg: &'static str,
-        fg: &'static str,
-        cursor: &'static str,
-        selection: &'static str,
+        bg: HexColor,
+        fg: HexColor,
+        cursor: HexColor,
+        selection: HexColor,
     ) -> Self {
         ColorTheme {
-            name: "monocolor",
+            name: Cow::Borrowed("monocolor"),
             dark,
             bg,
             cursor,
@@ -151,6 +665,12 @@ impl ColorTheme {
             strs: fg,
             comments: fg,
             special: fg,
+            tags: fg,
+            attributes: fg,
+            headings: fg,
+            emphasis: fg,
+            styles: ThemeStyles::NONE,
+            chrome: ChromeColors::NONE,
         }
     }
 }
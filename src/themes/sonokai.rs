@@ -1,22 +1,53 @@
-use super::ColorTheme;
+use super::{ChromeColors, ColorTheme, FontStyle, HexColor, ThemeStyles};
+use std::borrow::Cow;
+
+// Font styles of sonokai highlight groups.
+const SONOKAI_STYLES: ThemeStyles = ThemeStyles {
+    comments: FontStyle::ITALIC,      // Comment
+    attributes: FontStyle::ITALIC,    // htmlArg
+    headings: FontStyle::BOLD,        // Title
+    emphasis: FontStyle::ITALIC,      // markdownItalic
+    hyperlinks: FontStyle::UNDERLINE, // markdownUrl
+    ..ThemeStyles::NONE
+};
 
 impl ColorTheme {
     ///  Original Author: sainnhe <https://github.com/sainnhe/sonokai>
     ///  Modified by p4ymak <https://github.com/p4ymak>
     pub const SONOKAI: ColorTheme = ColorTheme {
-        name: "Sonokai",
+        name: Cow::Borrowed("Sonokai"),
         dark: true,
-        bg: "#2c2e34",          // bg0
-        cursor: "#76cce0",      // blue
-        selection: "#444852",   // bg5
-        comments: "#7f8490",    // gray
-        functions: "#9ed072",   // green
-        keywords: "#fc5d7c",    // red
-        literals: "#e2e2e3",    // foreground
-        numerics: "#b39df3",    // purple
-        punctuation: "#7f8490", // gray
-        strs: "#e7c664",        // yellow
-        types: "#399ee6",       // blue
-        special: "#f39660",     // orange
+        bg: HexColor::hex("#2c2e34"),          // bg0
+        cursor: HexColor::hex("#76cce0"),      // blue
+        selection: HexColor::hex("#444852"),   // bg5
+        comments: HexColor::hex("#7f8490"),    // gray
+        functions: HexColor::hex("#9ed072"),   // green
+        keywords: HexColor::hex("#fc5d7c"),    // red
+        literals: HexColor::hex("#e2e2e3"),    // foreground
+        numerics: HexColor::hex("#b39df3"),    // purple
+        punctuation: HexColor::hex("#7f8490"), // gray
+        strs: HexColor::hex("#e7c664"),        // yellow
+        types: HexColor::hex("#399ee6"),       // blue
+        special: HexColor::hex("#f39660"),     // orange
+        tags: HexColor::hex("#fc5d7c"),        // red
+        attributes: HexColor::hex("#76cce0"),  // blue
+        headings: HexColor::hex("#f39660"),    // orange
+        emphasis: HexColor::hex("#b39df3"),    // purple
+        styles: SONOKAI_STYLES,
+        chrome: ChromeColors {
+            gutter: HexColor::hex("#2c2e34"),             // bg0
+            line_numbers: HexColor::hex("#595f6f"),       // grey_dim
+            active_line_number: HexColor::hex("#e2e2e3"), // foreground
+            current_line: HexColor::hex("#33353f"),       // bg1
+            matching_bracket: HexColor::hex("#414550"),   // bg4
+            whitespace: HexColor::hex("#3b3e48"),         // bg3
+            indent_guides: HexColor::hex("#363944"),      // bg2
+            search_match: HexColor::hex("#e7c66440"),     // yellow
+            error: HexColor::hex("#fc5d7c"),              // red
+            warning: HexColor::hex("#e7c664"),            // yellow
+            info: HexColor::hex("#76cce0"),               // blue
+            popup: HexColor::hex("#363944"),              // bg2
+            popup_selection: HexColor::hex("#85d3f2"),    // bg_blue
+        },
     };
 }